@@ -0,0 +1,13 @@
+use prediput::prompting::{AsyncPrompter, Predicate};
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let prompter = AsyncPrompter::new("That's not a number.")
+        .pred(Predicate::new("Pick a number from 1 to 10.", Box::new(|n: &u32| (1..=10).contains(n))))
+        .max_attempts(3);
+
+    match prompter.prompt("Pick a number: ").await {
+        Ok(n) => println!("You picked {n}"),
+        Err(e) => println!("Gave up: {e}"),
+    }
+}
@@ -0,0 +1,56 @@
+use crate::error::PromptResult;
+use crate::prompting::Prompter;
+
+/// Prompts for a hex string (pairs of `0-9a-fA-F` digits), decoding it into bytes.
+///
+/// The user is re-prompted with a message identifying the problem (see [`decode_hex()`]) until the input decodes.
+///
+/// # Errors
+/// Propogates the same errors as [`Prompter::prompt()`].
+pub fn input_hex(prompt: &str) -> PromptResult<Vec<u8>> {
+    Prompter::from_conversion_fn("", Box::new(decode_hex)).prompt(prompt)
+}
+
+/// Prompts for a base64 string (in the standard alphabet, with padding), decoding it into bytes.
+///
+/// The user is re-prompted with a message identifying the problem (see [`decode_base64()`]) until the input decodes.
+///
+/// # Errors
+/// Propogates the same errors as [`Prompter::prompt()`].
+#[cfg(feature = "base64")]
+pub fn input_base64(prompt: &str) -> PromptResult<Vec<u8>> {
+    Prompter::from_conversion_fn("", Box::new(decode_base64)).prompt(prompt)
+}
+
+/// Decodes a hex string (pairs of `0-9a-fA-F` digits) into bytes.
+///
+/// # Errors
+/// Returns a message naming the first invalid character and its (one-based) position, or noting that the string has an odd number of digits.
+pub fn decode_hex(val: &str) -> Result<Vec<u8>, String> {
+    if let Some((i, c)) = val.chars().enumerate().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid hex digit `{c}` at position {}.", i + 1));
+    }
+    if !val.len().is_multiple_of(2) {
+        return Err(format!("Hex input must have an even number of digits (found {}).", val.len()));
+    }
+
+    // every character is an ASCII hex digit at this point, so the string can be sliced per byte
+    (0..val.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&val[i..i + 2], 16).map_err(|err| err.to_string()))
+        .collect()
+}
+
+/// Decodes a base64 string (in the standard alphabet, with padding) into bytes.
+///
+/// # Errors
+/// Returns a message naming the first invalid character and its (one-based) position, or noting that the string has an invalid length (e.g. from missing or extra padding).
+#[cfg(feature = "base64")]
+pub fn decode_base64(val: &str) -> Result<Vec<u8>, String> {
+    base64::decode(val).map_err(|err| match err {
+        base64::DecodeError::InvalidByte(i, byte) | base64::DecodeError::InvalidLastSymbol(i, byte) => {
+            format!("Invalid base64 character `{}` at position {}.", char::from(byte), i + 1)
+        }
+        base64::DecodeError::InvalidLength => format!("Base64 input has an invalid length ({}), which may be due to missing or extra padding.", val.len()),
+    })
+}
@@ -0,0 +1,161 @@
+use console::Key;
+
+use crate::error::PromptResult;
+use crate::{parse_answer, read_key, unrecognized_answer, TermTarget};
+use crate::testing::{StdTerminal, TerminalBackend};
+use crate::theme::default_theme;
+
+/// A configurable yes/no prompt, answered with a single keystroke.
+///
+/// By default, the keys are taken from the default [`Theme`](crate::theme::Theme) (`'y'` and `'n'`, unless changed), there is no default answer, and a hint is only shown once a default is set.
+#[must_use]
+pub struct Confirm<'a> {
+    /// The message printed ahead of the hint (if shown).
+    prompt: &'a str,
+    /// The key that answers "yes" (matched case-insensitively).
+    yes_key: char,
+    /// The key that answers "no" (matched case-insensitively).
+    no_key: char,
+    /// The answer given when enter is pressed, if any.
+    default: Option<bool>,
+    /// Determines whether to clear the prompt after an answer is given.
+    hide_after: bool,
+    /// Determines whether the keys (e.g. `[y/n]`) are shown after the prompt, if set explicitly; otherwise, they are shown when a default is set.
+    show_hint: Option<bool>,
+    /// The terminal stream that the prompt is rendered to.
+    target: TermTarget,
+    /// Determines whether the answer's key is written after the prompt once an answer is given.
+    show_answer: bool,
+}
+
+impl<'a> Confirm<'a> {
+    /// Creates a `Confirm` that prints `prompt` when prompting.
+    pub fn new(prompt: &'a str) -> Self {
+        let theme = default_theme();
+        Self {
+            prompt,
+            yes_key: theme.confirm_yes_char,
+            no_key: theme.confirm_no_char,
+            default: None,
+            hide_after: false,
+            show_hint: None,
+            target: TermTarget::Stdout,
+            show_answer: false,
+        }
+    }
+
+    /// Consumes the existing `Confirm` and returns a new `Confirm` that answers "yes" when `key` is pressed.
+    pub fn yes_key(mut self, key: char) -> Self {
+        self.yes_key = key;
+        self
+    }
+
+    /// Consumes the existing `Confirm` and returns a new `Confirm` that answers "no" when `key` is pressed.
+    pub fn no_key(mut self, key: char) -> Self {
+        self.no_key = key;
+        self
+    }
+
+    /// Consumes the existing `Confirm` and returns a new `Confirm` that answers `default` when enter is pressed.
+    /// Unless [`show_hint(false)`](Confirm::show_hint) is called, the hint (e.g. `[Y/n]`) is shown after the prompt.
+    pub fn default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Consumes the existing `Confirm` and returns a new `Confirm` that clears the prompt (if `hide_after`) once an answer is given.
+    pub fn hide_after(mut self, hide_after: bool) -> Self {
+        self.hide_after = hide_after;
+        self
+    }
+
+    /// Consumes the existing `Confirm` and returns a new `Confirm` that shows (if `show_hint`) the keys after the prompt, such as `[y/n]`.
+    /// If a default is set, its key is uppercased (e.g. `[Y/n]`). By default, the hint is only shown when a default is set.
+    pub fn show_hint(mut self, show_hint: bool) -> Self {
+        self.show_hint = Some(show_hint);
+        self
+    }
+
+    /// Consumes the existing `Confirm` and returns a new `Confirm` that writes the key of the given answer after the prompt (e.g. `Delete file? y`), then moves to the next line.
+    /// Has no effect if the prompt is cleared (see [`hide_after()`](Confirm::hide_after)).
+    pub fn show_answer(mut self) -> Self {
+        self.show_answer = true;
+        self
+    }
+
+    /// Consumes the existing `Confirm` and returns a new `Confirm` that is rendered to stderr instead of stdout.
+    pub fn to_stderr(mut self) -> Self {
+        self.target = TermTarget::Stderr;
+        self
+    }
+
+    /// Returns the message to display, including the hint if it is shown.
+    fn display_msg(&self) -> String {
+        if !self.show_hint.unwrap_or_else(|| self.default.is_some()) {
+            return self.prompt.to_string();
+        }
+
+        let (yes, no) = match self.default {
+            Some(true) => (self.yes_key.to_ascii_uppercase(), self.no_key.to_ascii_lowercase()),
+            Some(false) => (self.yes_key.to_ascii_lowercase(), self.no_key.to_ascii_uppercase()),
+            None => (self.yes_key, self.no_key),
+        };
+
+        // keep any trailing whitespace after the hint so that the answer still starts where the caller intended
+        let trimmed = self.prompt.trim_end();
+        format!("{trimmed} [{yes}/{no}]{}", &self.prompt[trimmed.len()..])
+    }
+
+    /// Prompts for a keystroke, returning true when the "yes" key is pressed or false when the "no" key is pressed.
+    /// If a default is set, pressing enter returns it; otherwise, enter is ignored.
+    ///
+    /// When not [interactive](crate::is_interactive), the first word of a line of stdin is read as the answer instead.
+    ///
+    /// # Errors
+    /// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`](crate::PromptError::Cancelled) if Ctrl+C is pressed.
+    pub fn prompt(&self) -> PromptResult<bool> {
+        if !self.target.is_interactive() {
+            return self.prompt_with_backend(&StdTerminal);
+        }
+
+        let term = self.target.term();
+        term.hide_cursor()?;
+        term.write_str(&self.display_msg())?;
+
+        let is_confirmed = loop { // per keystroke
+            match read_key(&term)? {
+                Key::Char(c) if c.eq_ignore_ascii_case(&self.yes_key) => { break true; }
+                Key::Char(c) if c.eq_ignore_ascii_case(&self.no_key) => { break false; }
+                Key::Enter => {
+                    if let Some(default) = self.default {
+                        break default;
+                    }
+                }
+                _ => {}
+            }
+        };
+        if self.hide_after {
+            term.clear_line()?;
+        } else if self.show_answer {
+            term.write_line(&(if is_confirmed { self.yes_key } else { self.no_key }).to_string())?;
+        }
+        term.show_cursor()?;
+        Ok(is_confirmed)
+    }
+
+    /// Prompts for an answer like [`prompt()`](Confirm::prompt), but reads a line from (and prints the prompt to) `backend` instead of reading a keystroke from the terminal.
+    /// The first word of the line is read as the answer, and an empty line returns the default (if set).
+    ///
+    /// This is mostly useful for testing, with a [`MockTerminal`](crate::testing::MockTerminal).
+    ///
+    /// # Errors
+    /// Propogates any I/O errors from `backend`, and returns [`PromptError::ValidationFailed`](crate::PromptError::ValidationFailed) if the answer is not recognized.
+    pub fn prompt_with_backend(&self, backend: &dyn TerminalBackend) -> PromptResult<bool> {
+        let answer = backend.input(&self.display_msg())?;
+        match (parse_answer(&answer, self.yes_key, self.no_key), self.default) {
+            (Some(is_confirmed), _) => Ok(is_confirmed),
+            (None, Some(default)) if answer.is_empty() => Ok(default),
+            _ => Err(unrecognized_answer(&answer)),
+        }
+    }
+}
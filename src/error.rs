@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+
+/// The result of a prompt, which fails with a [`PromptError`].
+pub type PromptResult<T> = Result<T, PromptError>;
+
+/// The ways in which a prompt can fail.
+#[derive(Debug)]
+pub enum PromptError {
+    /// An I/O error occurred while reading the input or writing to the terminal, such as stdin being closed (an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof)).
+    Io(io::Error),
+    /// The user cancelled the prompt, by pressing Ctrl+C or (where a prompt is cancellable) escape.
+    Cancelled,
+    /// The number of failed attempts reached the limit (see [`Prompter::max_attempts()`](crate::prompting::Prompter::max_attempts)).
+    MaxAttemptsExceeded,
+    /// The prompt was configured with a default that is invalid, such as an out-of-bounds default option; the message describes the problem.
+    InvalidDefault(String),
+    /// An answer was rejected where it cannot be retried, such as an unrecognized answer read from piped input; the message describes the problem.
+    ValidationFailed(String),
+}
+
+impl Display for PromptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Cancelled => write!(f, "the prompt was cancelled"),
+            Self::MaxAttemptsExceeded => write!(f, "the maximum number of attempts was reached"),
+            Self::InvalidDefault(msg) | Self::ValidationFailed(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl Error for PromptError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Wraps `err` as [`PromptError::Io`], except that an error of kind [`Interrupted`](io::ErrorKind::Interrupted) (which reading keystrokes returns when Ctrl+C is pressed) becomes [`PromptError::Cancelled`].
+impl From<io::Error> for PromptError {
+    fn from(err: io::Error) -> Self {
+        if err.kind() == io::ErrorKind::Interrupted {
+            Self::Cancelled
+        } else {
+            Self::Io(err)
+        }
+    }
+}
+
+/// Converts the error back into an [`io::Error`], for callers that return [`io::Result`]:
+/// [`Cancelled`](PromptError::Cancelled) becomes an error of kind [`Interrupted`](io::ErrorKind::Interrupted), [`MaxAttemptsExceeded`](PromptError::MaxAttemptsExceeded) of kind [`Other`](io::ErrorKind::Other),
+/// [`InvalidDefault`](PromptError::InvalidDefault) of kind [`InvalidInput`](io::ErrorKind::InvalidInput), and [`ValidationFailed`](PromptError::ValidationFailed) of kind [`InvalidData`](io::ErrorKind::InvalidData).
+impl From<PromptError> for io::Error {
+    fn from(err: PromptError) -> Self {
+        let kind = match err {
+            PromptError::Io(err) => return err,
+            PromptError::Cancelled => io::ErrorKind::Interrupted,
+            PromptError::MaxAttemptsExceeded => io::ErrorKind::Other,
+            PromptError::InvalidDefault(_) => io::ErrorKind::InvalidInput,
+            PromptError::ValidationFailed(_) => io::ErrorKind::InvalidData,
+        };
+        Self::new(kind, err)
+    }
+}
@@ -0,0 +1,81 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use crate::error::PromptResult;
+use crate::input;
+use crate::theme::default_theme;
+
+/// The environment variable holding the user's home directory, which `~` expands to.
+#[cfg(windows)]
+const HOME_VAR: &str = "USERPROFILE";
+/// The environment variable holding the user's home directory, which `~` expands to.
+#[cfg(not(windows))]
+const HOME_VAR: &str = "HOME";
+
+/// Prompts the user for a file path until a valid one is entered.
+///
+/// If `must_exist` is true, the path must exist; if `must_be_dir` is true, the path must be an existing directory.
+/// A leading `~` is expanded to the user's home directory. A message describing the problem is printed whenever a path is rejected.
+///
+/// # Errors
+/// As this function internally uses the [`input()`](crate::input) function, errors that occur there (including stdin being closed) will be propgated to the caller.
+pub fn prompt_path(prompt: &str, must_exist: bool, must_be_dir: bool) -> PromptResult<PathBuf> {
+    prompt_valid_path(prompt, |path| {
+        if (must_exist || must_be_dir) && !path.exists() {
+            Err("That path does not exist.")
+        } else if must_be_dir && !path.is_dir() {
+            Err("That path is not a directory.")
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Prompts the user for the path of a file (or directory) to create, until a valid one is entered.
+///
+/// The path's parent directory must exist, but the path itself must not.
+/// A leading `~` is expanded to the user's home directory. A message describing the problem is printed whenever a path is rejected.
+///
+/// # Errors
+/// As this function internally uses the [`input()`](crate::input) function, errors that occur there (including stdin being closed) will be propgated to the caller.
+pub fn prompt_path_new(prompt: &str) -> PromptResult<PathBuf> {
+    prompt_valid_path(prompt, |path| {
+        // a relative path with a single component has an empty parent, which is the current directory
+        let parent = path.parent().filter(|parent| !parent.as_os_str().is_empty()).unwrap_or_else(|| Path::new("."));
+        if !parent.is_dir() {
+            Err("The parent directory does not exist.")
+        } else if path.exists() {
+            Err("That path already exists.")
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Prompts the user for a path until one is entered that passes `check`, printing the message returned by `check` whenever it fails.
+fn prompt_valid_path(prompt: &str, check: impl Fn(&Path) -> Result<(), &'static str>) -> PromptResult<PathBuf> {
+    let invalid_style = default_theme().invalid_style;
+    loop {
+        let val = input(prompt)?;
+        let path = expand_home(&val);
+        let result = if val.is_empty() { Err("Please enter a path.") } else { check(&path) };
+
+        match result {
+            Ok(()) => return Ok(path),
+            Err(msg) => println!("{}", invalid_style.apply_to(msg)),
+        }
+    }
+}
+
+/// Expands a leading `~` in `path` to the user's home directory.
+/// The path is returned as-is if it does not start with `~` or the home directory is unknown.
+fn expand_home(path: &str) -> PathBuf {
+    let Some(rest) = path.strip_prefix('~') else {
+        return PathBuf::from(path);
+    };
+    if !rest.is_empty() && !rest.starts_with(['/', '\\']) {
+        return PathBuf::from(path); // e.g. `~user`, which is not supported
+    }
+
+    env::var_os(HOME_VAR).map_or_else(|| PathBuf::from(path), |home| PathBuf::from(home).join(rest.trim_start_matches(['/', '\\'])))
+}
@@ -0,0 +1,90 @@
+use std::collections::VecDeque;
+use std::io;
+
+use console::{Key, Term};
+
+use crate::error::PromptResult;
+use crate::{is_interactive, read_key, read_line};
+
+/// A text prompt that remembers its previous inputs, which can be recalled with the up and down arrow keys.
+///
+/// History is kept in memory only, for as long as the `HistoryInput` lives.
+#[must_use]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HistoryInput {
+    /// The previous (non-empty) inputs, from oldest to newest.
+    history: VecDeque<String>,
+}
+
+impl HistoryInput {
+    /// Creates a `HistoryInput` with an empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the previous (non-empty) inputs, from oldest to newest.
+    #[must_use]
+    pub fn history(&self) -> &VecDeque<String> {
+        &self.history
+    }
+
+    /// Prompts for an input, reading it keystroke by keystroke.
+    ///
+    /// The up arrow key replaces the input with the previous entry in the history, and the down arrow key goes forward again (back to the input being typed, past the newest entry).
+    /// Backspace removes the last character, and enter finishes the input, which is added to the history unless it is empty.
+    ///
+    /// When not [interactive](crate::is_interactive), a line is read instead (and still added to the history).
+    ///
+    /// # Errors
+    /// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`](crate::PromptError::Cancelled) if Ctrl+C is pressed.
+    pub fn input(&mut self, prompt: &str) -> PromptResult<String> {
+        let line = if is_interactive() { self.read_interactive(prompt)? } else { read_line()? };
+        if !line.is_empty() {
+            self.history.push_back(line.clone());
+        }
+        Ok(line)
+    }
+
+    /// Reads an input keystroke by keystroke, recalling entries from the history with the up and down arrow keys.
+    fn read_interactive(&self, prompt: &str) -> io::Result<String> {
+        let term = Term::stdout();
+        let mut buffer = String::new();
+        let mut draft = String::new(); // the input being typed, restored when going forward past the newest entry
+        let mut recalled_index: Option<usize> = None;
+        loop { // per keystroke
+            term.clear_line()?;
+            term.write_str(&format!("{prompt}{buffer}"))?;
+
+            match read_key(&term)? {
+                Key::Enter => { break; }
+                Key::ArrowUp => {
+                    let prev = recalled_index.map_or_else(|| self.history.len().checked_sub(1), |i| Some(i.saturating_sub(1)));
+                    if let Some(prev) = prev {
+                        if recalled_index.is_none() {
+                            draft = buffer.clone();
+                        }
+                        buffer.clone_from(&self.history[prev]);
+                        recalled_index = Some(prev);
+                    }
+                }
+                Key::ArrowDown => {
+                    if let Some(i) = recalled_index {
+                        if i + 1 < self.history.len() {
+                            buffer.clone_from(&self.history[i + 1]);
+                            recalled_index = Some(i + 1);
+                        } else {
+                            buffer.clone_from(&draft);
+                            recalled_index = None;
+                        }
+                    }
+                }
+                Key::Backspace => { buffer.pop(); }
+                Key::Char(c) if !c.is_control() => { buffer.push(c); }
+                _ => {}
+            }
+        }
+
+        term.write_line("")?;
+        Ok(buffer)
+    }
+}
@@ -12,17 +12,196 @@
 
 #![allow(clippy::missing_const_for_fn)]
 
+#![allow(clippy::cargo_common_metadata)]
+
 /// Module for text-based prompts with custom validation.
 pub mod prompting;
-/// Module for single-select dialogs.
+/// Module for single-select and multi-select dialogs.
 pub mod select;
+/// Module for commonly used [`Predicate`](prompting::Predicate)s.
+///
+/// Each function returns a fully constructed predicate, which can be passed directly into [`Prompter::pred()`](prompting::Prompter::pred):
+///
+/// ```no_run
+/// use prediput::predicates::{in_range, non_empty};
+/// use prediput::prompting::Prompter;
+///
+/// let age = Prompter::new("Please enter a whole number.")
+///     .pred(in_range(1u32, 120u32, "Please enter an age between 1 and 120."))
+///     .prompt("Age: ");
+///
+/// let name = Prompter::new("Please enter a name.")
+///     .pred(non_empty("Your name can't be empty."))
+///     .prompt("Name: ");
+/// ```
+pub mod predicates;
+/// Module for the spinner shown while long-running tasks are in progress.
+pub mod spinner;
+/// Module for styling shared across prompt types.
+pub mod theme;
+/// Module for pre-styled prefixes, for use with [`Select::new()`](select::Select::new).
+/// The prefixes are styled with raw ANSI escape codes so that they can be constants.
+pub mod style;
+/// Module for prompting for file paths.
+pub mod fs;
+/// Module for the configurable yes/no prompt.
+pub mod confirm;
+/// Module for running several prompts in sequence as a multi-step wizard.
+pub mod wizard;
+/// Module for text prompts that recall previous inputs.
+pub mod history;
+/// Module for prompting for binary data, such as hex and base64 strings.
+pub mod binary;
+/// Module for prompting for URLs.
+#[cfg(feature = "url")]
+pub mod web;
+/// Module for the error type returned by prompts.
+pub mod error;
+/// Module for running prompts against a mock terminal in tests.
+pub mod testing;
 /// Module for library macros.
 mod macros;
 
+use std::fmt::Display;
 use std::io;
-use std::io::{stdout, Write};
+use std::io::{stdout, IsTerminal, Write};
 use std::str::FromStr;
-use console::{Key, Term};
+use std::sync::mpsc::{self, Receiver, Sender, RecvTimeoutError, TryRecvError};
+use std::sync::{Mutex, OnceLock, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+use console::{style, Key, Term};
+use unicode_segmentation::UnicodeSegmentation;
+
+pub use error::{PromptError, PromptResult};
+pub use select::{select, select_multiple, Decolor};
+pub use theme::set_default_theme;
+use theme::default_theme;
+
+/// Returns whether prompts run interactively, which requires both stdin and stdout to be terminals.
+///
+/// When they are not (e.g. when input is piped in with `echo "42" | my_app`), every prompt reads its answer as a line of stdin instead of reading keystrokes, and no escape codes are written:
+/// - text prompts (such as [`input()`](crate::input)) read the line without printing the prompt
+/// - confirmations (such as [`confirm()`](crate::confirm)) read the first word of the line as `'y'` or `'n'`
+/// - selections (such as [`Select::prompt()`](select::Select::prompt)) read an option's display text or (zero-based) index
+/// - timed prompts do not count down, and [`enter_to_continue()`](crate::enter_to_continue) and [`any_key_continue()`](crate::any_key_continue) return immediately
+///
+/// Answers that a confirmation or selection does not recognize are not retried; [`PromptError::ValidationFailed`] is returned instead.
+#[must_use]
+pub fn is_interactive() -> bool {
+    TermTarget::Stdout.is_interactive()
+}
+
+/// The terminal stream that a prompt is rendered to.
+///
+/// Rendering to stderr keeps interactive menus out of stdout, which is useful when an application's real output is piped (e.g. `my_app | less`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TermTarget {
+    /// Render to stdout (the default).
+    #[default]
+    Stdout,
+    /// Render to stderr.
+    Stderr,
+}
+
+impl TermTarget {
+    /// Returns the terminal for the stream.
+    pub(crate) fn term(self) -> Term {
+        match self {
+            Self::Stdout => Term::stdout(),
+            Self::Stderr => Term::stderr(),
+        }
+    }
+
+    /// Returns whether prompts rendered to the stream run interactively, which requires both stdin and the stream to be terminals (see [`is_interactive()`]).
+    #[must_use]
+    pub fn is_interactive(self) -> bool {
+        io::stdin().is_terminal() && self.term().is_term()
+    }
+}
+
+/// Which whitespace is trimmed from an input (see [`input_with_trim()`]).
+///
+/// The line ending is always removed, whichever the mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TrimMode {
+    /// Trim leading and trailing whitespace (the default).
+    #[default]
+    Both,
+    /// Trim leading whitespace only.
+    Left,
+    /// Trim trailing whitespace only.
+    Right,
+    /// Keep all whitespace.
+    None,
+}
+
+impl TrimMode {
+    /// Returns `line` without its line ending (if any), trimmed according to the mode.
+    pub(crate) fn apply(self, line: &str) -> &str {
+        let line = line.strip_suffix('\n').map_or(line, |line| line.strip_suffix('\r').unwrap_or(line));
+        match self {
+            Self::Both => line.trim(),
+            Self::Left => line.trim_start(),
+            Self::Right => line.trim_end(),
+            Self::None => line,
+        }
+    }
+}
+
+/// Reads a line from stdin, trimmed, without printing anything.
+pub(crate) fn read_line() -> io::Result<String> {
+    read_line_with_trim(TrimMode::Both)
+}
+
+/// Reads a line from stdin, trimmed according to `mode`, without printing anything.
+fn read_line_with_trim(mode: TrimMode) -> io::Result<String> {
+    let mut line = String::new();
+    match take_pending_key()? { // typed after a timed prompt's countdown ran out
+        Some(Key::Enter) => return Ok(String::new()),
+        Some(Key::Char(c)) if !c.is_control() => {
+            print!("{c}");
+            stdout().flush()?;
+            line.push(c);
+        }
+        _ => {}
+    }
+    line.push_str(&next_stdin_line()?);
+    Ok(mode.apply(&line).to_string())
+}
+
+/// Reads the next line from stdin (including its line break), taking it from the shared stdin reader thread once that exists (see [`stdin_lines`]),
+/// since reading stdin directly would then race the thread for lines.
+fn next_stdin_line() -> io::Result<String> {
+    let Some(lines) = STDIN_LINES.get() else {
+        return read_stdin_line();
+    };
+    let line = lines.lock().unwrap_or_else(std::sync::PoisonError::into_inner).recv();
+    line.unwrap_or_else(|_| Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stdin was closed")))
+}
+
+/// Reads a line from stdin directly (including its line break), returning an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) once stdin is closed.
+fn read_stdin_line() -> io::Result<String> {
+    let mut line = String::new();
+    if io::stdin().read_line(&mut line)? == 0 {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stdin was closed"));
+    }
+    Ok(line)
+}
+
+/// Returns the `'y'` (true) or `'n'` (false) answer given by the first word of `line`, or `None` if it starts with neither key.
+pub(crate) fn parse_answer(line: &str, yes_key: char, no_key: char) -> Option<bool> {
+    match line.split_whitespace().next()?.chars().next()? {
+        c if c.eq_ignore_ascii_case(&yes_key) => Some(true),
+        c if c.eq_ignore_ascii_case(&no_key) => Some(false),
+        _ => None,
+    }
+}
+
+/// Creates the error returned when a non-interactive `answer` (see [`is_interactive()`]) is not recognized.
+pub(crate) fn unrecognized_answer(answer: &str) -> PromptError {
+    PromptError::ValidationFailed(format!("unrecognized answer: {answer:?}"))
+}
 
 /// A convenience function to get a user input.
 /// Note that this function uses the [`print!`](std::print) macro (before flushing stdout) so that the programmer can make prompts in-line.
@@ -30,17 +209,184 @@ use console::{Key, Term};
 /// This function continues to prompt the user until an input can be properly converted to the desired type; `invalid_msg` is printed when the attempted conversion fails.
 ///
 /// # Errors
-/// As this function internally uses the [`input()`](crate::input) function, errors that occur there will be propgated to the caller.
+/// As this function internally uses the [`input()`](crate::input) function, errors that occur there (including stdin being closed) will be propgated to the caller.
 #[must_use = "this function returns the converted value, which should be used"]
-pub fn prompt<T: FromStr>(prompt: &str, invalid_msg: &str) -> T {
+pub fn prompt<T: FromStr>(prompt: &str, invalid_msg: &str) -> PromptResult<T> {
     loop {
-        if let Ok(s) = input(prompt) {
-            if let Ok(val) = s.trim().parse::<T>() {
-                return val;
-            }
+        if let Ok(val) = input(prompt)?.parse::<T>() {
+            return Ok(val);
         }
     
-        println!("{}", invalid_msg);
+        println!("{}", default_theme().invalid_style.apply_to(invalid_msg));
+    }
+}
+
+/// Like [`prompt()`](crate::prompt), but also re-prompts (printing `valid_err`) if `valid_fn` returns `false` for the converted value; `parse_err` is printed when the conversion fails.
+///
+/// This is a shorthand for a [`Prompter`](prompting::Prompter) with a single [`Predicate`](prompting::Predicate), for quick one-off validation.
+///
+/// # Errors
+/// Propogates the same errors as [`Prompter::prompt()`](prompting::Prompter::prompt).
+#[must_use = "this function returns the converted value, which should be used"]
+pub fn input_validated<T: FromStr, F: Fn(&T) -> bool>(prompt: &str, parse_err: &str, valid_fn: F, valid_err: &str) -> PromptResult<T> {
+    prompting::Prompter::new(parse_err).pred(prompting::Predicate::new(valid_err, Box::new(valid_fn))).prompt(prompt)
+}
+
+/// Like [`prompt()`](crate::prompt), but prompts only once, returning `default` if the input cannot be converted to the desired type instead of re-prompting.
+///
+/// # Errors
+/// Propogates any errors from [`input()`](crate::input).
+#[must_use = "this function returns the converted value, which should be used"]
+pub fn prompt_or_default<T: FromStr>(prompt: &str, default: T) -> PromptResult<T> {
+    Ok(input(prompt)?.parse().unwrap_or(default))
+}
+
+/// Like [`prompt()`](crate::prompt), but also re-prompts (printing `invalid_msg`) if the converted value is outside of `min` and `max` (inclusive).
+///
+/// # Errors
+/// As this function internally uses the [`input()`](crate::input) function, errors that occur there (including stdin being closed) will be propgated to the caller.
+#[must_use = "this function returns the converted value, which should be used"]
+pub fn prompt_bounded<T: PartialOrd + FromStr + Display>(prompt: &str, min: T, max: T, invalid_msg: &str) -> PromptResult<T> {
+    let bounds = min..=max;
+    loop {
+        match input(prompt)?.parse::<T>() {
+            Ok(val) if bounds.contains(&val) => return Ok(val),
+            _ => println!("{}", default_theme().invalid_style.apply_to(invalid_msg)),
+        }
+    }
+}
+
+/// Like [`prompt()`](crate::prompt), but also re-prompts if the converted value is not one of `options`.
+///
+/// `invalid_msg` is printed when the conversion fails; when the value is not one of `options`, it is followed by the list of valid values (e.g. `valid values: easy, medium, hard`).
+///
+/// # Errors
+/// As this function internally uses the [`input()`](crate::input) function, errors that occur there (including stdin being closed) will be propgated to the caller.
+#[must_use = "this function returns the converted value, which should be used"]
+pub fn prompt_one_of<T: FromStr + Eq + Display>(prompt: &str, options: &[T], invalid_msg: &str) -> PromptResult<T> {
+    let invalid_style = default_theme().invalid_style;
+    loop {
+        match input(prompt)?.parse::<T>() {
+            Ok(val) if options.contains(&val) => return Ok(val),
+            Ok(_) => {
+                let valid_values = options.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+                println!("{}", invalid_style.apply_to(format!("{invalid_msg} (valid values: {valid_values})")));
+            }
+            Err(_) => println!("{}", invalid_style.apply_to(invalid_msg)),
+        }
+    }
+}
+
+/// Repeatedly prompts the user with `item_prompt`, collecting each converted input until the list is finished.
+///
+/// If `done_on_empty` is true, submitting an empty input finishes the list. Otherwise, empty inputs are rejected like inputs that cannot be converted,
+/// so the list can only be finished by [`prompt_repeat_bounded()`](crate::prompt_repeat_bounded) reaching its maximum size.
+/// `invalid_msg` is printed whenever an input is rejected.
+///
+/// # Errors
+/// As this function internally uses the [`input()`](crate::input) function, errors that occur there (including stdin being closed) will be propgated to the caller.
+#[must_use = "this function returns the converted values, which should be used"]
+pub fn prompt_repeat<T: FromStr>(item_prompt: &str, invalid_msg: &str, done_on_empty: bool) -> PromptResult<Vec<T>> {
+    prompt_repeat_bounded(item_prompt, invalid_msg, done_on_empty, 0, usize::MAX)
+}
+
+/// Like [`prompt_repeat()`](crate::prompt_repeat), but collects between `min` and `max` values (inclusive).
+///
+/// A message is printed if the user tries to finish the list before `min` values are collected, or to add a value once `max` values are collected.
+/// If `done_on_empty` is false, the list is finished as soon as `max` values are collected.
+///
+/// # Errors
+/// As this function internally uses the [`input()`](crate::input) function, errors that occur there (including stdin being closed) will be propgated to the caller.
+#[must_use = "this function returns the converted values, which should be used"]
+pub fn prompt_repeat_bounded<T: FromStr>(item_prompt: &str, invalid_msg: &str, done_on_empty: bool, min: usize, max: usize) -> PromptResult<Vec<T>> {
+    let invalid_style = default_theme().invalid_style;
+    let mut values = Vec::new();
+
+    loop {
+        if values.len() >= max && !done_on_empty {
+            return Ok(values);
+        }
+
+        let val = input(item_prompt)?;
+        let rejected_msg = if val.is_empty() && done_on_empty {
+            if values.len() >= min {
+                return Ok(values);
+            }
+            format!("Please enter at least {min} values.")
+        } else if val.is_empty() {
+            invalid_msg.to_string()
+        } else if values.len() >= max {
+            format!("Please enter at most {max} values.")
+        } else if let Ok(val) = val.parse::<T>() {
+            values.push(val);
+            continue;
+        } else {
+            invalid_msg.to_string()
+        };
+
+        println!("{}", invalid_style.apply_to(rejected_msg));
+    }
+}
+
+/// Like [`prompt()`](crate::prompt), but returns `default` if the user does not start typing within `timeout_secs` seconds.
+///
+/// A countdown is displayed after the prompt until the user presses a key, at which point the countdown stops and the user may take as long as needed.
+/// Input is read keystroke by keystroke (backspace removes the last character), and the countdown is removed from the line on both paths.
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn prompt_timed<T: FromStr>(prompt: &str, invalid_msg: &str, default: T, timeout_secs: u64) -> PromptResult<T> {
+    if !is_interactive() {
+        return loop { // an empty line stands in for the timer firing
+            let val = read_line()?;
+            if val.is_empty() {
+                break Ok(default);
+            }
+            if let Ok(val) = val.parse::<T>() {
+                break Ok(val);
+            }
+            println!("{}", default_theme().invalid_style.apply_to(invalid_msg));
+        };
+    }
+
+    let term = Term::stdout();
+    let mut deadline = Some(Instant::now() + Duration::from_secs(timeout_secs));
+    let mut buffer = String::new();
+    term.hide_cursor()?;
+
+    loop {
+        let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+        term.clear_line()?;
+        term.write_str(&format!("{prompt}{buffer}{}", remaining.map(countdown_suffix).unwrap_or_default()))?;
+
+        if remaining.is_some_and(|remaining| remaining.is_zero()) {
+            term.clear_line()?;
+            term.write_line(prompt)?;
+            term.show_cursor()?;
+            return Ok(default);
+        }
+
+        let Some(key) = recv_key(remaining)? else {
+            continue; // the countdown ticked
+        };
+        let key = check_interrupt(&term, key)?;
+        deadline = None; // the user started typing, so stop the countdown
+
+        match key {
+            Key::Enter => {
+                term.clear_line()?;
+                term.write_line(&format!("{prompt}{buffer}"))?;
+                if let Ok(val) = buffer.trim().parse::<T>() {
+                    term.show_cursor()?;
+                    return Ok(val);
+                }
+                term.write_line(&default_theme().invalid_style.apply_to(invalid_msg).to_string())?;
+                buffer.clear();
+            }
+            Key::Backspace => { buffer.pop(); }
+            Key::Char(c) if !c.is_control() => { buffer.push(c); }
+            _ => {}
+        }
     }
 }
 
@@ -48,69 +394,612 @@ pub fn prompt<T: FromStr>(prompt: &str, invalid_msg: &str) -> T {
 /// Note that this function uses the [`print!`](std::print) macro and flushes `stdout` for printing, so that the programmer can make prompts in-line.
 ///
 /// # Errors
-/// Propogates any internal I/O errors.
-pub fn input(prompt: &str) -> io::Result<String> {
-    let stdin = io::stdin();
+/// Propogates any internal I/O errors, and returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when stdin is closed.
+pub fn input(prompt: &str) -> PromptResult<String> {
+    input_with_trim(prompt, TrimMode::Both)
+}
+
+/// Like [`input()`](crate::input), but only trims the whitespace selected by `mode`, for inputs where leading or trailing whitespace is significant.
+///
+/// # Errors
+/// Propogates the same errors as [`input()`](crate::input).
+pub fn input_with_trim(prompt: &str, mode: TrimMode) -> PromptResult<String> {
+    if is_interactive() {
+        print!("{prompt}");
+        stdout().flush()?;
+    }
+    Ok(read_line_with_trim(mode)?)
+}
+
+/// Like [`input()`](crate::input), but returns `default` if an empty input is submitted.
+///
+/// ` [<default>]` is appended to the prompt (ahead of any trailing whitespace), as with [`Prompter::with_default()`](prompting::Prompter::with_default).
+///
+/// # Errors
+/// Propogates any errors from [`input()`](crate::input).
+pub fn input_with_default(prompt: &str, default: &str) -> PromptResult<String> {
+    let trimmed = prompt.trim_end();
+    let val = input(&format!("{trimmed} [{default}]{}", &prompt[trimmed.len()..]))?;
+    Ok(if val.is_empty() { default.to_string() } else { val })
+}
 
-    let mut input = String::new();
-    print!("{}", prompt);
+/// Prompts for an input of at most `max_len` characters, reading it keystroke by keystroke.
+///
+/// A dimmed `(N/max)` counter is shown to the right of the input, and keystrokes that would exceed `max_len` are refused with a bell.
+/// Characters are counted as grapheme clusters, so that e.g. `"é"` counts once regardless of how it is encoded.
+/// Backspace removes the last character and enter finishes the input (removing the counter).
+///
+/// When not [interactive](is_interactive), a line is read instead, and truncated to `max_len` characters.
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn input_chars(prompt: &str, max_len: usize) -> PromptResult<String> {
+    if !is_interactive() {
+        return Ok(read_line()?.graphemes(true).take(max_len).collect());
+    }
+
+    let term = Term::stdout();
+    let mut buffer = String::new();
+    loop { // per keystroke
+        let counter = format!("({}/{max_len})", buffer.graphemes(true).count());
+        term.clear_line()?;
+        term.write_str(&format!("{prompt}{buffer} {}", style(&counter).dim()))?;
+        term.move_cursor_left(counter.len() + 1)?;
+
+        match read_key(&term)? {
+            Key::Enter => { break; }
+            Key::Backspace => {
+                let last_len = buffer.graphemes(true).next_back().map_or(0, str::len);
+                buffer.truncate(buffer.len() - last_len);
+            }
+            Key::Char(c) if !c.is_control() => {
+                buffer.push(c);
+                if buffer.graphemes(true).count() > max_len {
+                    buffer.pop();
+                    term.write_str("\x07")?; // bell
+                }
+            }
+            _ => {}
+        }
+    }
+
+    term.clear_line()?;
+    term.write_line(&format!("{prompt}{buffer}"))?;
+    Ok(buffer)
+}
+
+/// Prompts for multiple lines of text, finishing when a blank line is entered.
+/// Otherwise behaves like [`input_multiline_until()`](crate::input_multiline_until).
+///
+/// # Errors
+/// Propogates any internal I/O errors, and returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when stdin is closed.
+pub fn input_multiline(prompt: &str) -> PromptResult<String> {
+    input_multiline_until(prompt, "")
+}
+
+/// Prompts for multiple lines of text, finishing when a line containing only `end_sentinel` (ignoring surrounding whitespace) is entered.
+///
+/// A dimmed help line explaining how to finish is shown below the prompt. The lines are joined with `\n`, excluding the sentinel line;
+/// individual lines are kept as typed (including indentation), and only trailing newlines at the very end are trimmed.
+///
+/// # Errors
+/// Propogates any internal I/O errors, and returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when stdin is closed.
+pub fn input_multiline_until(prompt: &str, end_sentinel: &str) -> PromptResult<String> {
+    if is_interactive() {
+        println!("{prompt}");
+        let help = if end_sentinel.is_empty() { "(Enter blank line to finish)".to_string() } else { format!("(Enter {end_sentinel} on its own line to finish)") };
+        println!("{}", style(help).dim());
+    }
+
+    let mut lines = Vec::new();
+    loop {
+        let line = next_stdin_line()?;
+        let line = line.trim_end_matches(['\n', '\r']);
+        if line.trim() == end_sentinel {
+            break;
+        }
+        lines.push(line.to_string());
+    }
+
+    Ok(lines.join("\n").trim_end_matches('\n').to_string())
+}
+
+/// Like [`input()`](crate::input), but returns `Ok(None)` immediately if a line has not been entered yet, rather than waiting for one.
+///
+/// Intended to be called repeatedly, such as once per frame of a render loop, until it returns a line.
+/// The prompt is printed by the first call, and again by the first call after a line is returned. Lines are read by a background thread shared with [`InputPoller`].
+///
+/// Once that thread exists, blocking line prompts (such as [`input()`](crate::input)) take their lines from it instead of reading stdin themselves.
+/// Keystroke prompts (such as [`confirm()`](crate::confirm) or [`password()`](crate::password)) still read the terminal directly, so they must not be used
+/// while a line is being polled for, or the thread and the prompt would compete for what is typed.
+///
+/// # Errors
+/// Propogates the same errors as [`input()`](crate::input).
+pub fn input_nonblocking(prompt: &str) -> PromptResult<Option<String>> {
+    static POLLER: Mutex<Option<InputPoller>> = Mutex::new(None);
+
+    let mut poller = POLLER.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    let line = poller.get_or_insert_with(|| InputPoller::new(prompt)).poll()?;
+    if line.is_some() {
+        *poller = None; // print the prompt again for the next line
+    }
+    drop(poller);
+    Ok(line)
+}
+
+/// Polls for lines of input without blocking, for applications that cannot wait on [`input()`](crate::input) (such as those with a render loop).
+///
+/// Lines are read (and trimmed) by a background thread, which is shared by every `InputPoller` and lives for the rest of the program,
+/// as a blocking read of stdin cannot be cancelled. Each line is received by only one poller (or blocking line prompt).
+///
+/// See [`input_nonblocking()`](crate::input_nonblocking) for how polling interacts with the blocking prompts.
+#[must_use]
+#[derive(Clone, Copy, Debug)]
+pub struct InputPoller {
+    _private: (),
+}
+
+impl InputPoller {
+    /// Prints `prompt` (like [`input()`](crate::input)) and creates a poller for the line answering it.
+    pub fn new(prompt: &str) -> Self {
+        if is_interactive() {
+            print!("{prompt}");
+            let _ = stdout().flush(); // the prompt is cosmetic, so polling can still go ahead
+        }
+        Self { _private: () }
+    }
+
+    /// Returns the next line of input if one has been entered, or `Ok(None)` otherwise (without blocking).
+    ///
+    /// # Errors
+    /// Propogates any internal I/O errors, and returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when stdin is closed.
+    pub fn poll(&self) -> PromptResult<Option<String>> {
+        let lines = match stdin_lines().try_lock() {
+            Ok(lines) => lines,
+            Err(TryLockError::Poisoned(err)) => err.into_inner(),
+            Err(TryLockError::WouldBlock) => return Ok(None), // a blocking line prompt is waiting for the next line
+        };
+        match lines.try_recv() {
+            Ok(line) => Ok(Some(TrimMode::Both.apply(&line?).to_string())),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stdin was closed").into()),
+        }
+    }
+}
+
+/// The channel of (untrimmed) lines read by the shared stdin reader thread, once spawned (see [`stdin_lines`]).
+static STDIN_LINES: OnceLock<Mutex<Receiver<io::Result<String>>>> = OnceLock::new();
+
+/// Returns the channel of lines read by the shared stdin reader thread, spawning the thread on first use.
+///
+/// The thread exits after the first error (such as stdin being closed), which it sends through the channel.
+fn stdin_lines() -> &'static Mutex<Receiver<io::Result<String>>> {
+    STDIN_LINES.get_or_init(|| {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || loop {
+            let line = read_stdin_line();
+            let is_err = line.is_err();
+            if tx.send(line).is_err() || is_err {
+                break;
+            }
+        });
+        Mutex::new(rx)
+    })
+}
+
+/// Reads an input keystroke by keystroke on the line above (that of the rejected input), showing `error` after it (see [`Prompter::with_inline_error()`](prompting::Prompter::with_inline_error)).
+///
+/// When not [interactive](crate::is_interactive), `error` is printed on its own line and a line is read instead.
+pub(crate) fn input_with_inline_error(prompt: &str, error: &str) -> io::Result<String> {
+    if !is_interactive() {
+        println!("{error}");
+        return read_line_with_trim(TrimMode::None);
+    }
+
+    let term = Term::stdout();
+    let suffix = inline_error_suffix(error);
+    let suffix_width = console::measure_text_width(&suffix);
+    let mut buffer = String::new();
+    term.move_cursor_up(1)?;
+    loop { // per keystroke
+        term.clear_line()?;
+        term.write_str(&format!("{prompt}{buffer}{suffix}"))?;
+        term.move_cursor_left(suffix_width)?;
+
+        match read_key(&term)? {
+            Key::Enter => { break; }
+            Key::Backspace => { buffer.pop(); }
+            Key::Char(c) if !c.is_control() => { buffer.push(c); }
+            _ => {}
+        }
+    }
+
+    term.clear_line()?;
+    term.write_line(&format!("{prompt}{buffer}"))?;
+    Ok(buffer)
+}
+
+/// Returns `error` formatted (and dimmed) to follow a prompt on the same line, with any line breaks (from [`Prompter::report_all_errors()`](prompting::Prompter::report_all_errors)) joined by `; `.
+pub(crate) fn inline_error_suffix(error: &str) -> String {
+    style(format!("  ← {}", error.replace('\n', "; "))).dim().to_string()
+}
+
+/// Prompts for a secret value without echoing what is typed.
+///
+/// Keystrokes are read one at a time; backspace removes the last character and enter finishes the input.
+/// The cursor is hidden during input, and the line is cleared once enter is pressed.
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn password(prompt: &str) -> PromptResult<String> {
+    Ok(read_secret(prompt, None)?)
+}
+
+/// Prompts for a secret value, printing `mask` in place of each character typed.
+/// Otherwise behaves like [`password()`](crate::password).
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn password_masked(prompt: &str, mask: char) -> PromptResult<String> {
+    Ok(read_secret(prompt, Some(mask))?)
+}
+
+/// Reads a secret value keystroke by keystroke, printing `mask` (if any) for each character in the buffer.
+fn read_secret(prompt: &str, mask: Option<char>) -> io::Result<String> {
+    if !is_interactive() {
+        return read_line();
+    }
+
+    let term = Term::stdout();
+    term.hide_cursor()?;
+    print!("{prompt}");
     stdout().flush()?;
-    stdin.read_line(&mut input)?;
-    Ok(input.trim().to_string())
+
+    let mut secret = String::new();
+    loop { // per keystroke
+        match read_key(&term)? {
+            Key::Enter => { break; }
+            Key::Backspace => {
+                let removed = secret.pop();
+                if removed.is_some() && mask.is_some() {
+                    term.clear_chars(1)?;
+                }
+            }
+            Key::Char(c) if !c.is_control() => {
+                secret.push(c);
+                if let Some(mask) = mask {
+                    print!("{mask}");
+                    stdout().flush()?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    term.clear_line()?;
+    term.show_cursor()?;
+    Ok(secret)
 }
 
 /// Prompts for a keystroke (either `'y'` or `'n'`).
 /// Returns true when `'y'` is pressed, or false when `'n'` is pressed.
 ///
+/// This is a shorthand for a [`Confirm`](confirm::Confirm) with the default settings, whose keys can be changed by setting a default [`Theme`](theme::Theme) (see [`set_default_theme()`]).
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn confirm(prompt: &str, hide_after: bool) -> PromptResult<bool> {
+    confirm::Confirm::new(prompt).hide_after(hide_after).prompt()
+}
+
+/// Asks for a yes/no answer for each of `items`, which are `(label, prompt)` pairs, returning the answers in the same order.
+///
+/// Up to 3 items are confirmed one at a time, as with [`multi_confirm_sequential()`](crate::multi_confirm_sequential).
+/// For more, every item is listed at once in a [`MultiSelect`](select::MultiSelect), where the spacebar toggles the item under the cursor (to answer yes) and enter finishes; the list is cleared afterwards if `hide_after`.
+///
+/// # Errors
+/// Propogates the same errors as [`confirm()`](crate::confirm) and [`MultiSelect::prompt()`](select::MultiSelect::prompt).
+pub fn multi_confirm(items: &[(&str, &str)], hide_after: bool) -> PromptResult<Vec<bool>> {
+    if items.len() <= 3 {
+        return multi_confirm_sequential(items, hide_after);
+    }
+
+    let options = items.iter().enumerate().map(|(i, (label, prompt))| select::SelectOpt::new(format!("{label}: {prompt}"), None, i)).collect();
+    let multi_select = select::MultiSelect::new("> ".to_string(), options).aligned();
+    let multi_select = if hide_after { multi_select.clear_after() } else { multi_select };
+    let confirmed = multi_select.prompt("Toggle each item to confirm with space, then press enter:".to_string())?;
+    Ok((0..items.len()).map(|i| confirmed.contains(&i)).collect())
+}
+
+/// Asks for a yes/no answer for each of `items`, which are `(label, prompt)` pairs, one at a time (see [`confirm()`](crate::confirm)), returning the answers in the same order.
+///
+/// Each item is prompted as `<label>: <prompt>`, and cleared once answered if `hide_after`.
+///
 /// # Errors
-/// Propogates any errors that occur in the [`console`](console) crate dependency
-pub fn confirm(prompt: &str, hide_after: bool) -> io::Result<bool> {
+/// Propogates the same errors as [`confirm()`](crate::confirm).
+pub fn multi_confirm_sequential(items: &[(&str, &str)], hide_after: bool) -> PromptResult<Vec<bool>> {
+    items.iter().map(|(label, prompt)| confirm(&format!("{label}: {prompt}"), hide_after)).collect()
+}
+
+/// Asks the user to type `confirmation_word` (e.g. `"delete"`) to confirm a destructive action, returning `true` only if the input matches it exactly (case-sensitively).
+///
+/// The input is read once with [`input()`](crate::input); any other input (including an empty one) returns `false`.
+/// Unless `prompt` already mentions `confirmation_word`, ` Type "<word>" to confirm: ` is appended to it.
+///
+/// # Errors
+/// Propogates any errors from [`input()`](crate::input).
+pub fn confirm_word(prompt: &str, confirmation_word: &str) -> PromptResult<bool> {
+    let answer = if prompt.contains(confirmation_word) {
+        input(prompt)?
+    } else {
+        input(&format!("{} Type \"{confirmation_word}\" to confirm: ", prompt.trim_end()))?
+    };
+    Ok(answer == confirmation_word)
+}
+
+/// Like [`confirm()`](crate::confirm), but writes the key of the given answer after the prompt (e.g. `Delete file? y`) before moving to the next line, unless the prompt is cleared (if `hide_after`).
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn confirm_verbose(prompt: &str, hide_after: bool) -> PromptResult<bool> {
+    confirm::Confirm::new(prompt).hide_after(hide_after).show_answer().prompt()
+}
+
+/// Like [`confirm()`](crate::confirm), but first checks the environment variable `var_name` for an answer.
+///
+/// If the variable is set to `"true"`, `"yes"` or `"1"` (or `"false"`, `"no"` or `"0"`), matched case-insensitively, true (or false) is returned without displaying the prompt.
+/// Otherwise, a warning is printed (if the variable is set) and the user is prompted as usual.
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn confirm_env(prompt: &str, hide_after: bool, var_name: &str) -> PromptResult<bool> {
+    if let Ok(val) = std::env::var(var_name) {
+        match val.trim().to_ascii_lowercase().as_str() {
+            "true" | "yes" | "1" => return Ok(true),
+            "false" | "no" | "0" => return Ok(false),
+            _ => println!("{}", default_theme().invalid_style.apply_to(format!("Ignoring the value of `{var_name}`: expected true/yes/1 or false/no/0."))),
+        }
+    }
+    confirm(prompt, hide_after)
+}
+
+/// Like [`confirm()`](crate::confirm), but returns `default` if neither `'y'` nor `'n'` is pressed within `timeout_secs` seconds.
+///
+/// A countdown is displayed after the prompt, and is removed from the line once an answer is given (or the timer fires).
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn confirm_timed(prompt: &str, default: bool, timeout_secs: u64) -> PromptResult<bool> {
+    let theme = default_theme();
+    if !is_interactive() {
+        let answer = read_line()?; // an empty line stands in for the timer firing
+        return if answer.is_empty() {
+            Ok(default)
+        } else {
+            parse_answer(&answer, theme.confirm_yes_char, theme.confirm_no_char).ok_or_else(|| unrecognized_answer(&answer))
+        };
+    }
+
     let term = Term::stdout();
+    let deadline = Instant::now() + Duration::from_secs(timeout_secs);
     term.hide_cursor()?;
-    print!("{}", prompt);
+
+    let is_confirmed = loop { // per keystroke or countdown tick
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        term.clear_line()?;
+        term.write_str(&format!("{prompt}{}", countdown_suffix(remaining)))?;
+
+        if remaining.is_zero() {
+            break default;
+        }
+
+        match recv_key(Some(remaining))?.map(|key| check_interrupt(&term, key)).transpose()? {
+            Some(Key::Char(c)) if c.eq_ignore_ascii_case(&theme.confirm_yes_char) => { break true; }
+            Some(Key::Char(c)) if c.eq_ignore_ascii_case(&theme.confirm_no_char) => { break false; }
+            _ => {}
+        }
+    };
+    term.clear_line()?;
+    term.write_str(prompt)?;
+    term.show_cursor()?;
+    Ok(is_confirmed)
+}
+
+/// The shared keystroke reader, whose thread reads one keystroke from the terminal per request (see [`key_reader`]).
+struct KeyReader {
+    requests: Sender<()>,
+    keys: Receiver<io::Result<Key>>,
+    /// Whether a keystroke was requested but not yet received (such as when a countdown ran out first); the next receiver takes it.
+    is_pending: bool,
+}
+
+/// The shared keystroke reader, once spawned (see [`key_reader`]).
+static KEY_READER: OnceLock<Mutex<KeyReader>> = OnceLock::new();
+
+/// Returns the shared keystroke reader, spawning its thread on first use.
+///
+/// Keystrokes are only read on request, so the terminal is left alone while no prompt is waiting for one.
+fn key_reader() -> &'static Mutex<KeyReader> {
+    KEY_READER.get_or_init(|| {
+        let (request_tx, request_rx) = mpsc::channel();
+        let (key_tx, key_rx) = mpsc::channel();
+        thread::spawn(move || {
+            let term = Term::stdout();
+            for () in request_rx {
+                if key_tx.send(term.read_key_raw()).is_err() {
+                    break;
+                }
+            }
+        });
+        Mutex::new(KeyReader { requests: request_tx, keys: key_rx, is_pending: false })
+    })
+}
+
+/// Receives the next keystroke from the shared keystroke reader (see [`key_reader`]).
+/// If a countdown is running (`remaining` is `Some`), waits at most a second, returning `None` if no key was pressed in that time;
+/// the keystroke is then still requested, so it goes to whichever prompt reads the next one.
+fn recv_key(remaining: Option<Duration>) -> io::Result<Option<Key>> {
+    let disconnected = || io::Error::new(io::ErrorKind::BrokenPipe, "the keystroke reader stopped unexpectedly");
+    let mut reader = key_reader().lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    if !reader.is_pending {
+        reader.requests.send(()).map_err(|_| disconnected())?;
+        reader.is_pending = true;
+    }
+    let key = match remaining {
+        Some(remaining) => match reader.keys.recv_timeout(remaining.min(Duration::from_secs(1))) {
+            Ok(key) => key,
+            Err(RecvTimeoutError::Timeout) => return Ok(None),
+            Err(RecvTimeoutError::Disconnected) => return Err(disconnected()),
+        },
+        None => reader.keys.recv().map_err(|_| disconnected())?,
+    };
+    reader.is_pending = false;
+    drop(reader);
+    key.map(Some)
+}
+
+/// Receives the keystroke left requested by a timed prompt whose countdown ran out, if any (see [`recv_key`]).
+fn take_pending_key() -> io::Result<Option<Key>> {
+    let is_pending = KEY_READER.get().is_some_and(|reader| reader.lock().unwrap_or_else(std::sync::PoisonError::into_inner).is_pending);
+    if is_pending { recv_key(None) } else { Ok(None) }
+}
+
+/// Reads a keystroke from `term`, treating Ctrl+C as an interrupt (see [`check_interrupt`]).
+pub(crate) fn read_key(term: &Term) -> io::Result<Key> {
+    let key = recv_key(None)?.expect("a keystroke is always received without a countdown");
+    check_interrupt(term, key)
+}
+
+/// Returns `key`, unless it is Ctrl+C; then, the current line is cleared, the cursor is shown, and an error of kind [`Interrupted`](io::ErrorKind::Interrupted) is returned.
+fn check_interrupt(term: &Term, key: Key) -> io::Result<Key> {
+    if key != Key::CtrlC {
+        return Ok(key);
+    }
+
+    term.clear_line()?;
+    term.show_cursor()?;
+    Err(io::Error::new(io::ErrorKind::Interrupted, "the prompt was interrupted"))
+}
+
+/// Returns the (dimmed) countdown suffix for a timed prompt with `remaining` time left, rounded up to the second.
+fn countdown_suffix(remaining: Duration) -> String {
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    style(format!(" ({secs}s)")).dim().to_string()
+}
+
+/// Prompts for a keystroke (either `'y'`, `'n'`, or escape).
+/// Returns `Some(true)` when `'y'` is pressed, `Some(false)` when `'n'` is pressed, or `None` when escape is pressed.
+///
+/// The prompt is printed as-is, so it should mention the escape option (e.g. `[y/n/Esc]`) itself.
+///
+/// # Errors
+/// Propogates any errors that occur in the [`console`](console) crate dependency, and returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn confirm_ternary(prompt: &str, hide_after: bool) -> PromptResult<Option<bool>> {
+    let theme = default_theme();
+    if !is_interactive() {
+        let answer = read_line()?; // an empty line stands in for escape
+        return if answer.is_empty() {
+            Ok(None)
+        } else {
+            parse_answer(&answer, theme.confirm_yes_char, theme.confirm_no_char).map(Some).ok_or_else(|| unrecognized_answer(&answer))
+        };
+    }
+
+    let term = Term::stdout();
+    term.hide_cursor()?;
+    print!("{prompt}");
     stdout().flush()?;
 
-    let is_confirmed = loop { // per keystroke
-        let key = term.read_key()?;
+    let answer = loop { // per keystroke
+        let key = read_key(&term)?;
         match key {
-            Key::Char('y' | 'Y') => { break true; }
-            Key::Char('n' | 'N') => { break false; }
-            _ => continue
+            Key::Char(c) if c.eq_ignore_ascii_case(&theme.confirm_yes_char) => { break Some(true); }
+            Key::Char(c) if c.eq_ignore_ascii_case(&theme.confirm_no_char) => { break Some(false); }
+            Key::Escape => { break None; }
+            _ => {}
         }
     };
     if hide_after {
         term.clear_line()?;
     }
     term.show_cursor()?;
-    Ok(is_confirmed)
+    Ok(answer)
 }
 
 /// Waits (blocking) for the user to press enter.
 ///
 /// # Errors
+/// Propogates the same errors as [`enter_to_continue_with_msg()`](crate::enter_to_continue_with_msg).
+pub fn enter_to_continue() -> PromptResult<()> {
+    enter_to_continue_with_msg("Press enter to continue...")
+}
+
+/// Prints `msg`, then waits (blocking) for the user to press enter.
+///
+/// # Errors
 /// Propogates errors from the following methods:
 /// - [`Stdout::flush`](std::io::stdio::Stdout::flush)
 /// - [`Term::hide_cursor`]
 /// - [`Term::clear_line`]
 /// - [`Term::show_cursor`]
-pub fn enter_to_continue() -> io::Result<()> {
-    let term = Term::stdout();
+///
+/// Returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn enter_to_continue_with_msg(msg: &str) -> PromptResult<()> {
+    enter_to_continue_on(msg, TermTarget::Stdout)
+}
+
+/// Like [`enter_to_continue_with_msg()`](crate::enter_to_continue_with_msg), but returns how long the user took to press enter (e.g. for analytics or adaptive pacing).
+///
+/// When not [interactive](is_interactive), nothing is waited for, so the returned duration is close to zero.
+///
+/// # Errors
+/// Propogates the same errors as [`enter_to_continue_with_msg()`](crate::enter_to_continue_with_msg).
+pub fn enter_to_continue_timed(msg: &str) -> PromptResult<Duration> {
+    let start = Instant::now();
+    enter_to_continue_with_msg(msg)?;
+    Ok(start.elapsed())
+}
+
+/// Like [`enter_to_continue_with_msg()`](crate::enter_to_continue_with_msg), but renders `msg` to `target`.
+///
+/// # Errors
+/// Propogates the same errors as [`enter_to_continue_with_msg()`](crate::enter_to_continue_with_msg).
+pub fn enter_to_continue_on(msg: &str, target: TermTarget) -> PromptResult<()> {
+    if !target.is_interactive() {
+        return Ok(());
+    }
+
+    let term = target.term();
     term.hide_cursor()?;
-    print!("Press enter to continue...");
-    stdout().flush()?;
+    term.write_str(msg)?;
 
     loop {
-        if let Ok(key) = term.read_key() {
-            if key == Key::Enter {
+        match read_key(&term) {
+            Ok(Key::Enter) => {
                 term.clear_line()?;
                 term.show_cursor()?;
                 return Ok(());
             }
+            Ok(_) => {}
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {
+                term.show_cursor()?;
+                return Err(PromptError::Cancelled);
+            }
+            Err(err) => { return Err(err.into()); }
         }
     }
 }
 
-/// Waits (blocking) for the user to press a key. Panics if an error is propogated internally.
+/// Waits (blocking) for the user to press a key.
+///
+/// # Errors
+/// Propogates the same errors as [`any_key_continue_with_msg()`](crate::any_key_continue_with_msg).
+pub fn any_key_continue() -> PromptResult<()> {
+    any_key_continue_with_msg("Press any key to continue...")
+}
+
+/// Prints `msg`, then waits (blocking) for the user to press a key.
 ///
 /// # Errors
 /// Propogates errors from the following methods:
@@ -119,23 +1008,104 @@ pub fn enter_to_continue() -> io::Result<()> {
 /// - [`Term::clear_line`]
 /// - [`Term::show_cursor`]
 /// - [`Term::read_key`]
-pub fn any_key_continue() -> io::Result<()> {
-    let term = Term::stdout();
+///
+/// Returns [`PromptError::Cancelled`] if Ctrl+C is pressed.
+pub fn any_key_continue_with_msg(msg: &str) -> PromptResult<()> {
+    wait_for_key(msg).map(|_| ())
+}
+
+/// Prints `msg`, then waits (blocking) for the user to press a key, returning the key that was pressed.
+///
+/// The cursor is hidden while waiting, and the line is cleared once a key is pressed.
+/// When not [interactive](is_interactive), [`Key::Unknown`] is returned immediately.
+///
+/// # Errors
+/// Propogates the same errors as [`any_key_continue_with_msg()`](crate::any_key_continue_with_msg).
+pub fn wait_for_key(msg: &str) -> PromptResult<Key> {
+    wait_for_key_on(msg, TermTarget::Stdout)
+}
+
+/// Like [`wait_for_key()`](crate::wait_for_key), but renders `msg` to `target`.
+///
+/// # Errors
+/// Propogates the same errors as [`any_key_continue_with_msg()`](crate::any_key_continue_with_msg).
+pub fn wait_for_key_on(msg: &str, target: TermTarget) -> PromptResult<Key> {
+    if !target.is_interactive() {
+        return Ok(Key::Unknown);
+    }
+
+    let term = target.term();
     term.hide_cursor()?;
-    print!("Press any key to continue...");
-    stdout().flush()?;
-    term.read_key()?;
+    term.write_str(msg)?;
+    let key = read_key(&term)?;
     term.clear_line()?;
     term.show_cursor()?;
-    Ok(())
+    Ok(key)
 }
 
 /// Clears the terminal. Any errors that occur are propogated to the caller.
 ///
 /// # Errors
 /// Propogates any errors from [`Term::clear_screen`].
-pub fn clear_terminal() -> io::Result<()> {
+pub fn clear_terminal() -> PromptResult<()> {
     let term = Term::stdout();
     term.clear_screen()?;
     Ok(())
 }
+
+/// Clears the terminal by scrolling its content up and out of view (like pressing Ctrl+L in some shells), so that it can still be scrolled back to.
+///
+/// The cursor is moved to the top-left corner. Nothing is written when not [interactive](is_interactive).
+///
+/// # Errors
+/// Propogates any errors from [`Term::write_str`] and [`Term::move_cursor_to`].
+pub fn clear_terminal_soft() -> PromptResult<()> {
+    if !is_interactive() {
+        return Ok(());
+    }
+
+    let term = Term::stdout();
+    let (rows, _) = term.size();
+    term.write_str(&"\n".repeat(usize::from(rows)))?;
+    Ok(term.move_cursor_to(0, 0)?)
+}
+
+/// Clears everything below the first `lines_to_keep` lines of the terminal's viewport, leaving the cursor at the start of the first cleared line.
+///
+/// This keeps context (such as a header) on screen while the rest of it is redrawn. Nothing is written when not [interactive](is_interactive).
+///
+/// # Errors
+/// Propogates any errors from [`Term::move_cursor_to`] and [`Term::clear_to_end_of_screen`].
+pub fn clear_below(lines_to_keep: usize) -> PromptResult<()> {
+    if !is_interactive() {
+        return Ok(());
+    }
+
+    let term = Term::stdout();
+    term.move_cursor_to(0, lines_to_keep)?;
+    Ok(term.clear_to_end_of_screen()?)
+}
+
+/// Clears the `n` lines above the cursor, leaving the cursor at the start of the first cleared line.
+///
+/// # Errors
+/// Propogates any errors from [`Term::clear_last_lines`].
+pub fn clear_lines(n: usize) -> PromptResult<()> {
+    Ok(Term::stdout().clear_last_lines(n)?)
+}
+
+/// Moves the cursor up `n` lines.
+///
+/// # Errors
+/// Propogates any errors from [`Term::move_cursor_up`].
+pub fn move_up(n: usize) -> PromptResult<()> {
+    Ok(Term::stdout().move_cursor_up(n)?)
+}
+
+/// Moves the cursor down `n` lines.
+///
+/// # Errors
+/// Propogates any errors from [`Term::move_cursor_down`].
+pub fn move_down(n: usize) -> PromptResult<()> {
+    Ok(Term::stdout().move_cursor_down(n)?)
+}
@@ -1,10 +1,60 @@
 /// A macro to construct a [`SelectOpt`](crate::select::SelectOpt).
 #[macro_export] macro_rules! selopt {
     ( $x:expr, $y:expr, $z:expr ) => {
-        SelectOpt::new($x, Some($y), $z)
+        $crate::select::SelectOpt::new($x, Some($y), $z)
     };
 
     ( $x:expr, $z: expr ) => {
-        SelectOpt::new($x, None, $z)
+        $crate::select::SelectOpt::new($x, None, $z)
     }
-}
\ No newline at end of file
+}
+
+/// A macro to construct a [`Select`](crate::select::Select) in one expression.
+///
+/// The `prefix` and `options` come first; each option is a tuple of the arguments to [`selopt!`].
+/// They may be followed by the `aligned: bool`, `padding: usize` and `clear_after: bool` keywords, in any order, which call the builder methods of the same name.
+///
+/// ```
+/// use prediput::select;
+///
+/// let sel = select! {
+///     prefix: "> ",
+///     options: [
+///         ("Option A", 0),
+///         ("Option B", 1),
+///         ("Option C", 2),
+///     ],
+///     aligned: true,
+///     padding: 1,
+/// };
+/// assert_eq!(sel.render_frame(1), "  Option A\n> Option B\n  Option C");
+/// ```
+#[macro_export] macro_rules! select {
+    ( prefix: $prefix:expr, options: [ $( ( $($opt:expr),+ ) ),* $(,)? ] $(, $($rest:tt)*)? ) => {
+        $crate::select!(@builder $crate::select::Select::new($prefix, vec![ $( $crate::selopt!($($opt),+) ),* ]); $($($rest)*)?)
+    };
+
+    ( @builder $sel:expr; ) => {
+        $sel
+    };
+
+    ( @builder $sel:expr; aligned: $aligned:expr $(, $($rest:tt)*)? ) => {
+        $crate::select!(@builder { let sel = $sel; if $aligned { sel.aligned() } else { sel } }; $($($rest)*)?)
+    };
+
+    ( @builder $sel:expr; padding: $padding:expr $(, $($rest:tt)*)? ) => {
+        $crate::select!(@builder $sel.padding($padding); $($($rest)*)?)
+    };
+
+    ( @builder $sel:expr; clear_after: $clear_after:expr $(, $($rest:tt)*)? ) => {
+        $crate::select!(@builder { let sel = $sel; if $clear_after { sel.clear_after() } else { sel } }; $($($rest)*)?)
+    };
+
+    ( @builder $sel:expr; $($invalid:tt)* ) => {
+        compile_error!("expected one of the keywords `aligned: bool`, `padding: usize` or `clear_after: bool`")
+    };
+
+    ( $($invalid:tt)* ) => {
+        compile_error!("expected `prefix: ..., options: [...]`, optionally followed by the keywords `aligned`, `padding` or `clear_after`")
+    };
+}
@@ -0,0 +1,42 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::prompting::Predicate;
+
+/// Creates a [`Predicate`] that passes when the string is not empty.
+pub fn non_empty(validation_msg: &str) -> Predicate<'_, String> {
+    Predicate::new(validation_msg, Box::new(|s: &String| !s.is_empty()))
+}
+
+/// Creates a [`Predicate`] that passes when the string is at least `n` characters (grapheme clusters) long.
+pub fn min_length(n: usize, validation_msg: &str) -> Predicate<'_, String> {
+    Predicate::new(validation_msg, Box::new(move |s: &String| s.graphemes(true).count() >= n))
+}
+
+/// Creates a [`Predicate`] that passes when the string is at most `n` characters (grapheme clusters) long.
+pub fn max_length(n: usize, validation_msg: &str) -> Predicate<'_, String> {
+    Predicate::new(validation_msg, Box::new(move |s: &String| s.graphemes(true).count() <= n))
+}
+
+/// Creates a [`Predicate`] that passes when the value is within the inclusive range `[lo, hi]`.
+pub fn in_range<T>(lo: T, hi: T, validation_msg: &str) -> Predicate<'_, T>
+where
+    T: PartialOrd + 'static,
+{
+    Predicate::new(validation_msg, Box::new(move |val: &T| lo <= *val && *val <= hi))
+}
+
+/// Creates a [`Predicate`] that passes when the string is exactly equal to one of `options`.
+pub fn one_of<'a>(options: &[&str], validation_msg: &'a str) -> Predicate<'a, String> {
+    let options: Vec<String> = options.iter().map(ToString::to_string).collect();
+    Predicate::new(validation_msg, Box::new(move |s: &String| options.contains(s)))
+}
+
+/// Creates a [`Predicate`] that passes when the string matches the regular expression `pattern`.
+/// See [`Predicate::from_regex`].
+///
+/// # Errors
+/// Returns the [`regex::Error`] if `pattern` fails to compile.
+#[cfg(feature = "regex")]
+pub fn matches_pattern<'a>(pattern: &str, validation_msg: &'a str) -> Result<Predicate<'a, String>, regex::Error> {
+    Predicate::from_regex(pattern, validation_msg)
+}
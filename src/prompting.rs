@@ -1,41 +1,249 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::env;
+use std::fmt::Display;
+use std::io;
+use std::rc::Rc;
 use std::str::FromStr;
-use crate::input;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread::{self, JoinHandle};
+#[cfg(feature = "regex")]
+use std::sync::Arc;
+#[cfg(feature = "tokio")]
+use std::sync::OnceLock;
+#[cfg(feature = "regex")]
+use regex::Regex;
+#[cfg(feature = "url")]
+use url::Url;
+use console::{style, Style};
+use crate::error::{PromptError, PromptResult};
+use crate::spinner::Spinner;
+use crate::testing::{StdTerminal, TerminalBackend};
+use crate::theme::{default_theme, Theme};
+use crate::TrimMode;
+
+/// Boxed function that formats a prompt message (the first argument) together with the displayed form of a default value (the second argument).
+pub type DefaultFormatFn<'a> = Box<dyn Fn(&str, &str) -> String + 'a>;
+
+/// Boxed function that converts an input into a value, returning a message to print instead of the conversion error message (or an empty string to print it as-is) if it fails.
+pub type ConversionFn<'a, T> = Box<dyn Fn(&str) -> Result<T, String> + 'a>;
+
+/// Boxed function that transforms an input before it is converted (see [`Prompter::with_transform`]).
+type TransformFn<'a> = Box<dyn Fn(String) -> String + 'a>;
+
+/// Boxed function called before each attempt of a [`Prompter`], with the (one-based) attempt number (see [`Prompter::with_attempt_hook`]).
+type AttemptHookFn<'a> = Box<dyn Fn(usize) + 'a>;
+
+/// Boxed function that checks a value, returning the validation message of the failed check (if any).
+type CheckFn<'a, T> = Box<dyn Fn(&T) -> Result<(), Cow<'a, str>> + 'a>;
+
+/// Boxed function that checks a value against (and may update) the state of a stateful predicate (see [`Predicate::new_stateful`]).
+pub type StatefulFn<T, S> = Box<dyn Fn(&T, &mut S) -> bool>;
+
+/// The validation message of a [`Predicate`], which is either fixed or generated from the invalid value (see [`Predicate::with_message_fn`]).
+enum ValidationMsg<'a, T> {
+    Static(&'a str),
+    Dynamic(Rc<dyn Fn(&T) -> String + 'a>),
+}
+
+impl<'a, T> ValidationMsg<'a, T> {
+    /// Returns the message to report for `val`.
+    fn for_value(&self, val: &T) -> Cow<'a, str> {
+        match self {
+            Self::Static(msg) => Cow::Borrowed(msg),
+            Self::Dynamic(message_fn) => Cow::Owned(message_fn(val)),
+        }
+    }
+}
+
+impl<T> Clone for ValidationMsg<'_, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Static(msg) => Self::Static(msg),
+            Self::Dynamic(message_fn) => Self::Dynamic(Rc::clone(message_fn)),
+        }
+    }
+}
 
 /// Type used to validate a value of a type under one or more validation (boolean) functions.
 #[must_use]
 pub struct Predicate<'a, T>
 {
-    /// Function that determines whether the predicate passes.
-    boxed_validation_fn: Box<dyn Fn(&T) -> bool>,
-    /// Message passed back when validation fails.
-    validation_msg: &'a str,
+    /// Function that determines whether the predicate passes, returning the message of the check that failed otherwise.
+    boxed_validation_fn: CheckFn<'a, T>,
+    /// Message passed back when validation fails, which may be generated from the value (see [`Predicate::with_message_fn`]).
+    validation_msg: ValidationMsg<'a, T>,
+    /// The style applied to the validation message when a [`Prompter`] prints it, if any (see [`Predicate::with_style`]).
+    style: Option<Style>,
 }
 
 impl<'a, T> Predicate<'a, T> {
     /// Creates a [`Predicate`] with the following arguments:
     /// - A validation message `val_msg` that will be printed when validation fails
     /// - A validation function `val_fn`, wrapped in a [`Box`](std::boxed::Box), that will return a boolean indicating whether or not the the value being checked is valid
-    pub fn new(validation_msg: &'a str, validation_fn: Box<dyn Fn(&T) -> bool>) -> Self {
+    pub fn new(validation_msg: &'a str, validation_fn: Box<dyn Fn(&T) -> bool + 'a>) -> Self
+    where
+        T: 'a,
+    {
         Self {
-            validation_msg,
-            boxed_validation_fn: validation_fn,
+            validation_msg: ValidationMsg::Static(validation_msg),
+            boxed_validation_fn: Box::new(move |val: &T| if validation_fn(val) { Ok(()) } else { Err(Cow::Borrowed(validation_msg)) }),
+            style: None,
+        }
+    }
+
+    /// Creates a [`Predicate`] whose validation function can read and update `initial_state` on each call, such as to require that each value is unique across prompts.
+    ///
+    /// The state is kept in a [`RefCell`] owned by the predicate, so the predicate can be used like any other (e.g. passed to [`Prompter::pred()`]).
+    pub fn new_stateful<S: 'static>(initial_state: S, validation_msg: &'a str, validation_fn: StatefulFn<T, S>) -> Self
+    where
+        T: 'a,
+    {
+        let state = RefCell::new(initial_state);
+        Self::new(validation_msg, Box::new(move |val: &T| validation_fn(val, &mut state.borrow_mut())))
+    }
+
+    /// Creates a [`Predicate`] that validates the value derived from each value by `transform`, rather than the value itself (e.g. to check that an uppercased string is not a reserved word).
+    /// The value being checked is unchanged, so a [`Prompter`] still returns the original value.
+    pub fn transform_then_validate<U>(transform: Box<dyn Fn(&T) -> U + 'a>, validation_fn: Box<dyn Fn(&U) -> bool + 'a>, validation_msg: &'a str) -> Self
+    where
+        T: 'a,
+        U: 'a,
+    {
+        Self::new(validation_msg, Box::new(move |val: &T| validation_fn(&transform(val))))
+    }
+
+    /// Creates a [`Predicate`] whose validation message is generated from the invalid value by `message_fn`, so that it can reference the value (e.g. `"42 is not in range [1, 10]"`).
+    pub fn with_message_fn(validation_fn: Box<dyn Fn(&T) -> bool>, message_fn: Box<dyn Fn(&T) -> String>) -> Predicate<'static, T>
+    where
+        T: 'static,
+    {
+        let message_fn: Rc<dyn Fn(&T) -> String> = Rc::from(message_fn);
+        Predicate {
+            validation_msg: ValidationMsg::Dynamic(Rc::clone(&message_fn)),
+            boxed_validation_fn: Box::new(move |val: &T| if validation_fn(val) { Ok(()) } else { Err(Cow::Owned(message_fn(val))) }),
+            style: None,
+        }
+    }
+
+    /// Consumes the `Predicate` and returns one whose validation message is printed in `style` by a [`Prompter`], rather than in the prompter's default style.
+    ///
+    /// Without a style, messages of predicates added with [`pred()`](Prompter::pred) use the prompter's [`invalid_style`](crate::theme::Theme::invalid_style),
+    /// and messages of predicates added with [`warning_pred()`](Prompter::warning_pred) are printed in yellow.
+    /// The message returned by [`check()`](Predicate::check) is left unstyled, and combined predicates take the style of the leftmost predicate.
+    pub fn with_style(self, style: Style) -> Self {
+        Self { style: Some(style), ..self }
+    }
+
+    /// Applies the predicate's style (if any) to `msg`, or `default_style` otherwise.
+    fn style_msg<'m>(&self, msg: Cow<'m, str>, default_style: Option<&Style>) -> Cow<'m, str> {
+        match self.style.as_ref().or(default_style) {
+            Some(style) => Cow::Owned(style.apply_to(msg).to_string()),
+            None => msg,
         }
     }
 
     /// Calls the predicate's validation function, returning a boolean indicating if `val` passed (is valid).
     pub fn validate(&self, val: &T) -> bool {
+        self.check(val).is_ok()
+    }
+
+    /// Calls the predicate's validation function, returning the validation message to print if `val` failed.
+    ///
+    /// For predicates combined with [`and()`](Predicate::and), this is the message of whichever predicate failed first.
+    ///
+    /// # Errors
+    /// Returns the validation message when `val` is invalid.
+    pub fn check(&self, val: &T) -> Result<(), Cow<'a, str>> {
         (*self.boxed_validation_fn)(val)
     }
 
+    /// Returns the validation message to print for `val`, including messages generated from it (see [`with_message_fn()`](Predicate::with_message_fn)).
+    ///
+    /// If `val` passes, the message the predicate would report for it is returned instead (the leftmost predicate's, for combined predicates).
+    #[must_use = "the only purpose of the method is to return the validation message, which should be used"]
+    pub fn invalid_msg_for(&self, val: &T) -> String {
+        self.check(val).err().unwrap_or_else(|| self.validation_msg.for_value(val)).into_owned()
+    }
+
     /// Returns the predicate's validation message.
+    ///
+    /// For combined predicates, this is the message of the leftmost predicate; see [`check()`](Predicate::check) for the message of the predicate that actually failed.
+    /// The message is empty for predicates created with [`with_message_fn()`](Predicate::with_message_fn); use [`invalid_msg_for()`](Predicate::invalid_msg_for) for those.
     #[must_use = "the only purpose of the method is to return the validation message, which should be used"]
     pub fn invalid_msg(&self) -> &str {
-        self.validation_msg
+        match self.validation_msg {
+            ValidationMsg::Static(msg) => msg,
+            ValidationMsg::Dynamic(_) => "",
+        }
+    }
+}
+
+impl<'a, T: 'a> Predicate<'a, T> {
+    /// Combines two predicates into one that passes only when both pass.
+    ///
+    /// `self` is checked first, and the message of the first predicate to fail is reported.
+    pub fn and(self, other: Self) -> Self {
+        Self {
+            validation_msg: self.validation_msg.clone(),
+            style: self.style.clone(),
+            boxed_validation_fn: Box::new(move |val: &T| self.check(val).and_then(|()| other.check(val))),
+        }
+    }
+
+    /// Combines two predicates into one that passes when at least one of them passes.
+    ///
+    /// When both fail, the message of `self` is reported.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            validation_msg: self.validation_msg.clone(),
+            style: self.style.clone(),
+            boxed_validation_fn: Box::new(move |val: &T| self.check(val).or_else(|msg| other.check(val).map_err(|_| msg))),
+        }
+    }
+
+    /// Inverts the predicate, so that it passes only when `self` fails.
+    ///
+    /// The predicate's validation message is reported on failure, generated from the value for predicates created with [`with_message_fn()`](Predicate::with_message_fn).
+    #[allow(clippy::should_implement_trait)] // a `Not` impl would require callers to import `std::ops::Not` to chain it
+    pub fn not(self) -> Self {
+        let validation_msg = self.validation_msg.clone();
+        Self {
+            validation_msg: self.validation_msg.clone(),
+            style: self.style.clone(),
+            boxed_validation_fn: Box::new(move |val: &T| match self.check(val) {
+                Ok(()) => Err(validation_msg.for_value(val)),
+                Err(_) => Ok(()),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<'a> Predicate<'a, String> {
+    /// Creates a [`Predicate`] that passes when the string being checked matches the regular expression `pattern`.
+    ///
+    /// The pattern is compiled once, when the predicate is created, rather than on every validation.
+    ///
+    /// # Errors
+    /// Returns the [`regex::Error`] if `pattern` fails to compile.
+    pub fn from_regex(pattern: &str, validation_msg: &'a str) -> Result<Self, regex::Error> {
+        let regex = Arc::new(Regex::new(pattern)?);
+        Ok(Self::new(validation_msg, Box::new(move |s: &String| regex.is_match(s))))
+    }
+}
+
+#[cfg(feature = "url")]
+impl<'a> Predicate<'a, Url> {
+    /// Creates a [`Predicate`] that passes when the URL being checked uses the scheme `expected` (e.g. `"https"`), compared case-insensitively.
+    pub fn url_scheme(expected: &str, validation_msg: &'a str) -> Self {
+        let expected = expected.to_ascii_lowercase();
+        Self::new(validation_msg, Box::new(move |url: &Url| url.scheme() == expected))
     }
 }
 
 /// Type that is used to...
-/// 1. Validate **conversions** from a type that can be converted from a string (that is, it implements [`FromStr`])
+/// 1. Validate **conversions** from a type that can be converted from a string (that is, it implements [`FromStr`], or a conversion function is set with [`with_conversion_fn()`](Prompter::with_conversion_fn))
 /// 2. Validate the **value** of the type an input is being converted into.
 ///     - This is done using a vector of [`Predicate`]s.
 /// 3. Print conversion or validation messages when validation fails in the cases above, in the following order:
@@ -46,14 +254,53 @@ impl<'a, T> Predicate<'a, T> {
 ///
 /// Note that predicates are ordered first-in. In other words, the first predicate added to the prompter will be the first one tested (whereas the last one added will be tested last).
 #[must_use]
+#[allow(clippy::struct_excessive_bools)] // each flag is an independent builder option
 pub struct Prompter<'a, T>
-where
-    T: FromStr
 {
     /// Message passed back when conversion fails.
     conversion_err_msg: &'a str,
+    /// Converts each (transformed) input into a value.
+    conversion_fn: ConversionFn<'a, T>,
     /// Collection of predicates to evaluate before accepting the input.
     predicates: Vec<Predicate<'a, T>>,
+    /// Collection of predicates that print a warning when they fail, without rejecting the input.
+    warning_predicates: Vec<Predicate<'a, T>>,
+    /// Produces the value returned when an empty input is submitted, if a default is set.
+    default_fn: Option<Box<dyn Fn() -> T + 'a>>,
+    /// The displayed form of the default value, shown alongside the prompt message.
+    default_hint: String,
+    /// Custom formatter that combines the prompt message and the default's displayed form.
+    default_format: Option<DefaultFormatFn<'a>>,
+    /// The number of failed attempts after which prompting gives up, if any.
+    max_attempts: Option<usize>,
+    /// The message shown alongside a [`Spinner`] while the predicates are evaluated, if any.
+    spinner_msg: Option<&'a str>,
+    /// The style applied to the conversion and validation messages printed when an input is rejected.
+    invalid_style: Style,
+    /// Transformations applied (in order) to each trimmed input before it is converted.
+    transforms: Vec<TransformFn<'a>>,
+    /// The environment variable checked for a value before prompting, if any.
+    env_var: Option<&'a str>,
+    /// The character echoed in place of each typed character, if the input is masked.
+    echo_mask: Option<char>,
+    /// Determines whether nothing at all is echoed while the input is typed (see [`Prompter::noecho`]).
+    is_noecho: bool,
+    /// Determines whether validation messages are shown on the line of the retried prompt (see [`Prompter::with_inline_error`]).
+    has_inline_error: bool,
+    /// Determines whether the prompt line is cleared once an input is accepted (see [`Prompter::silent_on_success`]).
+    is_silent_on_success: bool,
+    /// The character that separates the values of an input read by [`Prompter::prompt_csv`].
+    csv_separator: char,
+    /// Determines whether every failing predicate's message is printed, rather than only the first one's.
+    reports_all_errors: bool,
+    /// Called before each attempt is prompted for, if set.
+    attempt_hook: Option<AttemptHookFn<'a>>,
+    /// Which whitespace is trimmed from each input before it is transformed and converted.
+    trim_mode: TrimMode,
+    /// The lines printed (followed by a blank line) above the prompt message.
+    description: &'a [&'a str],
+    /// Determines whether the description is printed before every attempt, rather than only the first.
+    repeats_description: bool,
 }
 
 impl<'a, T> Prompter<'a, T>
@@ -63,51 +310,702 @@ impl<'a, T> Prompter<'a, T>
     ///
     /// Use the [`pred()`](Prompter::pred) function to add [`Predicate`]s to use when validating the value of the converted type.
     pub fn new(conversion_err_msg: &'a str) -> Self {
-        Self {
-            conversion_err_msg,
-            predicates: Vec::new()
-        }
+        Self::from_preds(conversion_err_msg, Vec::new())
     }
     
     /// Creates a `Prompter` with a `conversion_err_msg` to print if the type conversion fails, and [`Predicate`]s to evaluate when an input is received.
     pub fn from_preds(conversion_err_msg: &'a str, predicates: Vec<Predicate<'a, T>>) -> Self {
+        Self::from_pred_iter(conversion_err_msg, predicates)
+    }
+
+    /// Creates a `Prompter` like [`from_preds()`](Prompter::from_preds), but takes the [`Predicate`]s from any iterator, such as one building them from configuration.
+    pub fn from_pred_iter(conversion_err_msg: &'a str, predicates: impl IntoIterator<Item = Predicate<'a, T>>) -> Self {
+        Self::from_conversion_fn(conversion_err_msg, Box::new(|val| val.parse().map_err(|_| String::new()))).with_preds(predicates)
+    }
+}
+
+impl<'a, T> Prompter<'a, T>
+{
+    /// Creates a `Prompter` that converts each input with `conversion_fn` instead of [`str::parse`], which allows prompting for types that do not implement [`FromStr`].
+    /// `conversion_err_msg` is printed if the conversion fails, unless `conversion_fn` returns a non-empty message to print instead.
+    pub fn from_conversion_fn(conversion_err_msg: &'a str, conversion_fn: ConversionFn<'a, T>) -> Self {
         Self {
             conversion_err_msg,
-            predicates
+            conversion_fn,
+            predicates: Vec::new(),
+            warning_predicates: Vec::new(),
+            default_fn: None,
+            default_hint: String::new(),
+            default_format: None,
+            max_attempts: None,
+            spinner_msg: None,
+            invalid_style: default_theme().invalid_style,
+            transforms: Vec::new(),
+            env_var: None,
+            echo_mask: None,
+            is_noecho: false,
+            has_inline_error: false,
+            is_silent_on_success: false,
+            csv_separator: ',',
+            reports_all_errors: false,
+            attempt_hook: None,
+            trim_mode: TrimMode::Both,
+            description: &[],
+            repeats_description: false,
         }
     }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that uses the styling of `theme`.
+    /// Builder calls made afterwards override the theme's values.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.invalid_style = theme.invalid_style.clone();
+        self
+    }
     
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that gives up after `n` failed attempts.
+    ///
+    /// An attempt fails when the input cannot be converted or when any predicate fails.
+    pub fn max_attempts(mut self, n: usize) -> Self {
+        self.max_attempts = Some(n);
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that prints `lines` (followed by a blank line) above the prompt message before every attempt,
+    /// including the re-prompts after an input is rejected. When interactive, the previous attempt is cleared first, and its invalid message is shown again below the description.
+    pub fn with_description(mut self, lines: &'a [&'a str]) -> Self {
+        self.description = lines;
+        self.repeats_description = true;
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that prints `lines` (followed by a blank line) above the prompt message before the first attempt only.
+    pub fn with_description_once(mut self, lines: &'a [&'a str]) -> Self {
+        self.description = lines;
+        self.repeats_description = false;
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that only trims the whitespace selected by `mode` from each input (see [`input_with_trim()`](crate::input_with_trim)),
+    /// instead of trimming both ends.
+    pub fn trim_mode(mut self, mode: TrimMode) -> Self {
+        self.trim_mode = mode;
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that calls `hook_fn` with the (one-based) attempt number before each attempt is prompted for,
+    /// such as to log failed attempts or update the terminal before the prompt is printed again.
+    ///
+    /// The hook is called for the first attempt too, but not once the limit set by [`max_attempts()`](Prompter::max_attempts) is reached.
+    pub fn with_attempt_hook(mut self, hook_fn: impl Fn(usize) + 'a) -> Self {
+        self.attempt_hook = Some(Box::new(hook_fn));
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that shows a [`Spinner`] (followed by `message`) while its predicates are evaluated.
+    ///
+    /// This is useful when a predicate is slow, such as one that hits the network or disk.
+    pub fn with_spinner(mut self, message: &'a str) -> Self {
+        self.spinner_msg = Some(message);
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that applies `transform_fn` to each (trimmed) input before it is converted and validated.
+    ///
+    /// Transformations compose, being applied in the order they are added. For example, `.with_transform(|s| s.to_lowercase())` allows case-insensitive conversions.
+    pub fn with_transform(mut self, transform_fn: impl Fn(String) -> String + 'a) -> Self {
+        self.transforms.push(Box::new(transform_fn));
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that first checks the environment variable `var_name` when prompting.
+    ///
+    /// If the variable is set and its value passes conversion and every predicate, that value is returned without displaying the prompt.
+    /// Otherwise, a warning is printed (if the variable is set) and the user is prompted as usual.
+    pub fn env_default(mut self, var_name: &'a str) -> Self {
+        self.env_var = Some(var_name);
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that converts each input with `conversion_fn` instead of [`str::parse`] (such as to expand `~` in a path).
+    ///
+    /// The conversion error message is printed if the conversion fails, unless `conversion_fn` returns a non-empty message to print instead.
+    /// Any predicates are evaluated against the converted value as usual.
+    pub fn with_conversion_fn(mut self, conversion_fn: ConversionFn<'a, T>) -> Self {
+        self.conversion_fn = conversion_fn;
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that includes the new predicates (after any existing ones).
+    fn with_preds(mut self, predicates: impl IntoIterator<Item = Predicate<'a, T>>) -> Self {
+        self.predicates.extend(predicates);
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that reads each input keystroke by keystroke, echoing `mask` in place of each character typed (like [`password_masked()`](crate::password_masked)).
+    ///
+    /// Backspace removes the last character (and its mask), and enter finishes the input, which is then converted and validated as usual.
+    pub fn with_echo_mask(mut self, mask: char) -> Self {
+        self.echo_mask = Some(mask);
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that reads each input keystroke by keystroke without echoing anything (like [`password()`](crate::password)),
+    /// not even a mask character. Backspace silently removes the last character, and enter finishes the input.
+    ///
+    /// Since the user gets no feedback on their typing, this is only appropriate for true secrets (such as authentication tokens); it takes precedence over [`with_echo_mask()`](Prompter::with_echo_mask).
+    pub fn noecho(mut self) -> Self {
+        self.is_noecho = true;
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that shows the message of a rejected input on the same line as the prompt, rather than on its own line.
+    ///
+    /// The rejected input is cleared and the prompt is rewritten as `{msg}  ← {error}` (dimmed), with the cursor placed after the prompt;
+    /// the retried input is then read keystroke by keystroke, where backspace removes the last character and enter finishes the input.
+    /// This does not apply to masked or hidden inputs, nor to the message printed when the last attempt fails (see [`max_attempts()`](Prompter::max_attempts)).
+    pub fn with_inline_error(mut self) -> Self {
+        self.has_inline_error = true;
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that separates the values read by [`prompt_csv()`](Prompter::prompt_csv) with `separator` (e.g. `';'`) instead of a comma.
+    pub fn csv_separator(mut self, separator: char) -> Self {
+        self.csv_separator = separator;
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that clears the prompt line once an input is accepted, leaving the cursor at its start so that the caller can overwrite it
+    /// (like [`Select::clear_after()`](crate::select::Select::clear_after)). Rejected inputs are left on screen, above their invalid messages.
+    /// Masked and [`noecho()`](Prompter::noecho) inputs already clear their own line, so nothing more is cleared for them.
+    pub fn silent_on_success(mut self) -> Self {
+        self.is_silent_on_success = true;
+        self
+    }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that prints the messages of every failing predicate (one per line) when an input is rejected,
+    /// instead of only the message of the first one to fail.
+    pub fn report_all_errors(mut self) -> Self {
+        self.reports_all_errors = true;
+        self
+    }
+
     /// Consumes the existing `Prompter` and returns a new `Prompter` that includes the new predicate.
     pub fn pred(mut self, predicate: Predicate<'a, T>) -> Self {
         self.predicates.push(predicate);
         self
     }
+
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that includes the new warning predicate.
+    ///
+    /// Unlike those added with [`pred()`](Prompter::pred), a warning predicate does not reject the input; once an input passes every other predicate,
+    /// the message of each failing warning predicate is printed (after a yellow `⚠`) and the value is accepted.
+    pub fn warning_pred(mut self, predicate: Predicate<'a, T>) -> Self {
+        self.warning_predicates.push(predicate);
+        self
+    }
     
+    /// Sets a custom formatter for the prompt message when a default is set (see [`with_default()`](Prompter::with_default)).
+    /// The formatter receives the prompt message and the displayed form of the default value.
+    pub fn default_format(mut self, format_fn: DefaultFormatFn<'a>) -> Self {
+        self.default_format = Some(format_fn);
+        self
+    }
+
+    /// Returns the message to display, including the default value's hint if a default is set.
+    fn display_msg(&self, msg: &str) -> String {
+        if self.default_fn.is_none() {
+            return msg.to_string();
+        }
+
+        if let Some(format_fn) = &self.default_format {
+            return format_fn(msg, &self.default_hint);
+        }
+
+        // keep any trailing whitespace after the hint so that the input still starts where the caller intended
+        let trimmed = msg.trim_end();
+        format!("{trimmed} [{}]{}", self.default_hint, &msg[trimmed.len()..])
+    }
+
+    /// Transforms and converts `val`, then evaluates each predicate against the converted value, in order.
+    /// Returns the message to print if either the conversion or a predicate fails.
+    fn evaluate(&self, val: &str) -> Result<T, Cow<'_, str>> {
+        let val = self.transforms.iter().fold(self.trim_mode.apply(val).to_string(), |val, transform_fn| transform_fn(val));
+        let val = (self.conversion_fn)(&val).map_err(|msg| if msg.is_empty() { Cow::Borrowed(self.conversion_err_msg) } else { Cow::Owned(msg) })?;
+
+        let _spinner = self.spinner_msg.map(Spinner::new); // stops once the predicates have been evaluated
+        if !self.reports_all_errors {
+            for p in &self.predicates {
+                p.check(&val).map_err(|msg| p.style_msg(msg, None))?;
+            }
+            return Ok(val); // at this point, all predicates pass
+        }
+
+        let invalid_msgs: Vec<Cow<'_, str>> = self.predicates.iter().filter_map(|p| p.check(&val).err().map(|msg| p.style_msg(msg, None))).collect();
+        if invalid_msgs.is_empty() {
+            Ok(val)
+        } else {
+            Err(Cow::Owned(invalid_msgs.join("\n")))
+        }
+    }
+
     /// Prompts the user for an input.
     /// This function will continue prompting if either the user's input cannot be converted to the desired type or if any of the predicates fail.
-    #[must_use] pub fn prompt(&self, msg: &str) -> T
+    ///
+    /// If a default is set and the user submits an empty input, the default is returned without evaluating any predicates.
+    ///
+    /// # Errors
+    /// Propogates any I/O errors from reading the input (including an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when stdin is closed), and returns [`PromptError::MaxAttemptsExceeded`] once the number of failed attempts reaches the limit set by [`max_attempts()`](Prompter::max_attempts).
+    pub fn prompt(&self, msg: &str) -> PromptResult<T>
     {
-        'input: loop
+        self.prompt_with_backend(msg, &StdTerminal)
+    }
+
+    /// Prompts the user for an input like [`prompt()`](Prompter::prompt), but reads the input from (and prints messages to) `backend` instead of the terminal.
+    ///
+    /// This is mostly useful for testing, with a [`MockTerminal`](crate::testing::MockTerminal).
+    ///
+    /// # Errors
+    /// Propogates any I/O errors from `backend` (including an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when it runs out of input), and returns [`PromptError::MaxAttemptsExceeded`] once the number of failed attempts reaches the limit set by [`max_attempts()`](Prompter::max_attempts).
+    pub fn prompt_with_backend(&self, msg: &str, backend: &dyn TerminalBackend) -> PromptResult<T> {
+        // the default is assumed to be pre-validated by the caller
+        self.prompt_until_valid(backend, &self.display_msg(msg), self.default_fn.as_deref(), |val| val, self.max_attempts, false)
+    }
+
+    /// Returns the value of the environment variable `env_var` if it is set, or prompts the user for an input like [`prompt()`](Prompter::prompt) if it is not.
+    ///
+    /// Unlike [`env_default()`](Prompter::env_default), an invalid value is not ignored; an error naming the variable is returned instead of prompting.
+    ///
+    /// # Errors
+    /// Returns [`PromptError::ValidationFailed`] if the variable's value is not valid unicode or fails conversion or any predicate,
+    /// and otherwise propogates the same errors as [`prompt()`](Prompter::prompt).
+    pub fn prompt_or_env(&self, msg: &str, env_var: &str) -> PromptResult<T> {
+        let invalid_env_err = |invalid_msg: &str| PromptError::ValidationFailed(format!("invalid value for environment variable `{env_var}`: {invalid_msg}"));
+        match env::var(env_var) {
+            Ok(val) => self.evaluate(&val).map_err(|invalid_msg| invalid_env_err(&invalid_msg)),
+            Err(env::VarError::NotPresent) => self.prompt(msg),
+            Err(err @ env::VarError::NotUnicode(_)) => Err(invalid_env_err(&err.to_string())),
+        }
+    }
+
+    /// Prompts the user for an input like [`prompt()`](Prompter::prompt), but gives up after `max` failed attempts (overriding [`max_attempts()`](Prompter::max_attempts)),
+    /// telling the user how many attempts remain after each failure (e.g. `(2 attempts remaining)`, or `Last attempt.` before the final one).
+    ///
+    /// # Errors
+    /// Propogates any I/O errors from reading the input (including an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when stdin is closed), and returns [`PromptError::MaxAttemptsExceeded`] once `max` attempts have failed.
+    pub fn prompt_with_attempts_display(&self, msg: &str, max: usize) -> PromptResult<T> {
+        self.prompt_until_valid(&StdTerminal, &self.display_msg(msg), self.default_fn.as_deref(), |val| val, Some(max), true)
+    }
+
+    /// Prompts the user for an optional input, returning `None` if an empty input is submitted.
+    /// Otherwise, this function will continue prompting if either the user's input cannot be converted to the desired type or if any of the predicates fail.
+    ///
+    /// Predicates are not evaluated for empty inputs, and any default set with [`with_default()`](Prompter::with_default) is neither shown nor used.
+    ///
+    /// # Errors
+    /// Propogates any I/O errors from reading the input (including an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when stdin is closed), and returns [`PromptError::MaxAttemptsExceeded`] once the number of failed attempts reaches the limit set by [`max_attempts()`](Prompter::max_attempts).
+    pub fn prompt_opt(&self, msg: &str) -> PromptResult<Option<T>> {
+        self.prompt_until_valid(&StdTerminal, msg, Some(&|| None), Some, self.max_attempts, false)
+    }
+
+    /// Prompts the user for a comma-separated list of values (e.g. `rust, cli, prompt`), returning them in order.
+    /// Each (trimmed) value is converted and checked against every predicate; empty values are skipped, so an empty input returns an empty list.
+    ///
+    /// This function will continue prompting until every value is valid, printing the message for each invalid value along with the value itself.
+    /// See [`csv_separator()`](Prompter::csv_separator) to separate the values with another character.
+    ///
+    /// # Errors
+    /// Propogates the same errors as [`prompt()`](Prompter::prompt).
+    pub fn prompt_csv(&self, msg: &str) -> PromptResult<Vec<T>> {
+        self.prompt_csv_with_backend(msg, &StdTerminal)
+    }
+
+    /// Prompts the user for a list of values like [`prompt_csv()`](Prompter::prompt_csv), but reads the input from (and prints messages to) `backend` instead of the terminal.
+    ///
+    /// # Errors
+    /// Propogates the same errors as [`prompt_with_backend()`](Prompter::prompt_with_backend).
+    pub fn prompt_csv_with_backend(&self, msg: &str, backend: &dyn TerminalBackend) -> PromptResult<Vec<T>> {
+        let mut failed_attempts = 0;
+        loop
+        {
+            if let Some(max) = self.max_attempts {
+                if failed_attempts >= max {
+                    return Err(PromptError::MaxAttemptsExceeded);
+                }
+            }
+
+            let line = backend.input_with_trim(msg, self.trim_mode)?;
+            let mut values = Vec::new();
+            let mut invalid_msgs = Vec::new();
+            for token in line.split(self.csv_separator).map(str::trim).filter(|token| !token.is_empty()) {
+                match self.evaluate(token) {
+                    Ok(val) => values.push(val),
+                    Err(invalid_msg) => invalid_msgs.push(format!("{invalid_msg} (`{}`)", style(token).bold())),
+                }
+            }
+            if invalid_msgs.is_empty() {
+                return Ok(values);
+            }
+
+            failed_attempts += 1;
+            for invalid_msg in invalid_msgs {
+                backend.print_line(&self.invalid_style.apply_to(invalid_msg).to_string())?;
+            }
+        }
+    }
+
+    /// Returns the value of the environment variable set with [`env_default()`](Prompter::env_default) if it passes conversion and every predicate.
+    /// A warning is printed to `backend` if the variable is set but its value is rejected.
+    fn env_value(&self, backend: &dyn TerminalBackend) -> PromptResult<Option<T>> {
+        let Some(var_name) = self.env_var else {
+            return Ok(None);
+        };
+        let Ok(val) = env::var(var_name) else {
+            return Ok(None);
+        };
+        match self.evaluate(&val) {
+            Ok(val) => {
+                self.print_warnings(&val, backend)?;
+                Ok(Some(val))
+            }
+            Err(invalid_msg) => {
+                backend.print_line(&self.invalid_style.apply_to(format!("Ignoring the value of `{var_name}`: {invalid_msg}")).to_string())?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Prints the message of each warning predicate that `val` fails to `backend` (see [`warning_pred()`](Prompter::warning_pred)).
+    fn print_warnings(&self, val: &T, backend: &dyn TerminalBackend) -> io::Result<()> {
+        for p in &self.warning_predicates {
+            if let Err(warning_msg) = p.check(val) {
+                let warning_msg = p.style_msg(warning_msg, Some(&Style::new().yellow()));
+                backend.print_line(&format!("{} {warning_msg}", style(WARNING_PREFIX).yellow()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Prompts with `msg` on `backend` until an input passes conversion and every predicate, mapping the accepted value with `on_valid`.
+    /// The value of the environment variable set with [`env_default()`](Prompter::env_default) is accepted without prompting, if it is valid.
+    /// If `on_empty` is set, its result is returned as soon as an empty input is submitted.
+    /// Gives up after `max_attempts` failed attempts (if set), printing the number of remaining attempts after each failure if `show_remaining`.
+    fn prompt_until_valid<R>(&self, backend: &dyn TerminalBackend, msg: &str, on_empty: Option<&dyn Fn() -> R>, on_valid: fn(T) -> R, max_attempts: Option<usize>, show_remaining: bool) -> PromptResult<R> {
+        if let Some(val) = self.env_value(backend)? {
+            return Ok(on_valid(val));
+        }
+
+        let mut failed_attempts = 0;
+        let mut inline_error: Option<String> = None; // the message of the rejected input, to show on the line of the retried prompt
+        let mut rejected_line: Option<String> = None; // the line of the rejected input, for the inline error to replace once the description is redrawn
+        let mut shown_error: Option<String> = None; // the invalid message printed for the rejected input, to show again once the description is redrawn
+        let mut shown_lines = 0; // the lines printed since the description, cleared before it is repeated
+        loop
         {
-            match input(msg) // essentially, if this matches an Err(_) result, repeat the loop. The error shouldn't make the program panic.
-            {
-                Ok(val) => { // now actually convert the value and test the predicates.
-                    if let Ok(val) = val.trim().parse::<T>() {
-                        for p in &self.predicates
-                        {
-                            if !p.validate(&val)
-                            {
-                                println!("{}", p.invalid_msg());
-                                continue 'input;
-                            }
-                        } // at this point, all predicates pass
-                        return val;
+            if let Some(max) = max_attempts {
+                if failed_attempts >= max {
+                    return Err(PromptError::MaxAttemptsExceeded);
+                }
+            }
+            if let Some(attempt_hook) = &self.attempt_hook {
+                attempt_hook(failed_attempts + 1);
+            }
+            if !self.description.is_empty() && (failed_attempts == 0 || self.repeats_description) {
+                let is_redrawn = failed_attempts > 0 && backend.can_clear_lines();
+                if is_redrawn {
+                    for _ in 0..shown_lines {
+                        backend.clear_last_line()?;
+                    }
+                }
+                for line in self.description {
+                    backend.print_line(line)?;
+                }
+                backend.print_line("")?;
+                shown_lines = self.description.len() + 1;
+                if is_redrawn {
+                    if let Some(error) = &shown_error {
+                        backend.print_line(error)?;
+                        shown_lines += error.lines().count();
                     }
-                    // at this point, the loop already continued if a predicate failed, and returned if all predicates pass (only case left is a conversion error)
-                    println!("{}", self.conversion_err_msg);
+                    if let (Some(_), Some(line)) = (&inline_error, &rejected_line) {
+                        backend.print_line(line)?; // replaced by the retried prompt, so not counted
+                    }
+                }
+            }
+
+            let val = match (self.echo_mask, inline_error.take()) {
+                _ if self.is_noecho => backend.input_hidden(msg)?,
+                (Some(mask), _) => backend.input_masked(msg, mask)?,
+                (None, Some(error)) => self.trim_mode.apply(&backend.input_with_inline_error(msg, &error)?).to_string(),
+                (None, None) => backend.input_with_trim(msg, self.trim_mode)?,
+            };
+            if self.echo_mask.is_none() && !self.is_noecho { // secret inputs clear their own line
+                shown_lines += 1;
+            }
+            if val.is_empty() {
+                if let Some(on_empty) = on_empty {
+                    return Ok(on_empty());
+                }
+            }
+
+            match self.evaluate(&val) {
+                Ok(val) => {
+                    if self.is_silent_on_success && self.echo_mask.is_none() && !self.is_noecho { // secret inputs clear their own line
+                        backend.clear_last_line()?;
+                    }
+                    self.print_warnings(&val, backend)?;
+                    return Ok(on_valid(val));
+                }
+                Err(invalid_msg) => {
+                    failed_attempts += 1;
+                    let remaining = max_attempts.filter(|_| show_remaining).map(|max| max.saturating_sub(failed_attempts));
+                    let invalid_msg = match remaining {
+                        Some(0) | None => invalid_msg.into_owned(),
+                        Some(1) => format!("{invalid_msg} Last attempt."),
+                        Some(remaining) => format!("{invalid_msg} ({remaining} attempts remaining)"),
+                    };
+                    if self.has_inline_error && self.echo_mask.is_none() && !self.is_noecho && max_attempts.is_none_or(|max| failed_attempts < max) {
+                        inline_error = Some(invalid_msg);
+                        rejected_line = Some(format!("{msg}{val}"));
+                        shown_error = None;
+                    } else {
+                        let error = self.invalid_style.apply_to(invalid_msg).to_string();
+                        backend.print_line(&error)?;
+                        shown_lines += error.lines().count();
+                        shown_error = Some(error);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The symbol printed ahead of the message of a failing warning predicate (see [`Prompter::warning_pred`]).
+const WARNING_PREFIX: &str = "⚠";
+
+impl<'a, T> Prompter<'a, T>
+    where T: Clone + Display + 'a
+{
+    /// Consumes the existing `Prompter` and returns a new `Prompter` that returns `default` when an empty input is submitted.
+    ///
+    /// The default bypasses any predicates, as it is presumed to be valid. Unless a custom format is set with [`default_format()`](Prompter::default_format), ` [<default>]` is appended to the prompt message.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default_hint = default.to_string();
+        self.default_fn = Some(Box::new(move || default.clone()));
+        self
+    }
+}
+
+impl<T> Prompter<'static, T>
+    where T: Send + 'static
+{
+    /// Prompts the user for an input like [`prompt()`](Prompter::prompt) on a background thread, returning a [`PromptHandle`] that receives the result once the prompt finishes.
+    ///
+    /// The `Prompter` is built on the background thread by `build_fn`, as its predicates and other functions are not required to be sent between threads.
+    pub fn prompt_nonblocking(build_fn: impl FnOnce() -> Self + Send + 'static, msg: &'static str) -> PromptHandle<T> {
+        let (sender, receiver) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            let _ = sender.send(build_fn().prompt(msg)); // the handle may have stopped listening
+        });
+
+        PromptHandle {
+            receiver,
+            handle: Some(handle),
+        }
+    }
+}
+
+/// The result of a prompt running on a background thread (see [`Prompter::prompt_nonblocking`]).
+///
+/// Dropping the handle waits for the background thread to finish.
+#[must_use = "dropping the handle blocks until the prompt finishes"]
+pub struct PromptHandle<T> {
+    /// Receives the result of the prompt once it finishes.
+    receiver: Receiver<PromptResult<T>>,
+    /// The background thread running the prompt, until it is joined.
+    handle: Option<JoinHandle<()>>,
+}
+
+impl<T> PromptHandle<T> {
+    /// Returns the result of the prompt if it has finished, or `None` if it is still running (without blocking).
+    #[must_use = "the result can only be received once"]
+    pub fn try_result(&self) -> Option<PromptResult<T>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(prompt_thread_err())),
+        }
+    }
+
+    /// Blocks until the prompt finishes, returning its result.
+    ///
+    /// # Errors
+    /// Propogates the same errors as [`Prompter::prompt()`], and returns an I/O error of kind [`Other`](io::ErrorKind::Other) if the background thread panicked.
+    pub fn wait(self) -> PromptResult<T> {
+        self.receiver.recv().unwrap_or_else(|_| Err(prompt_thread_err()))
+    }
+}
+
+impl<T> Drop for PromptHandle<T> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join(); // a panic has already been reported by the thread
+        }
+    }
+}
+
+/// Creates the error returned when the background thread of a [`PromptHandle`] stops without sending a result.
+fn prompt_thread_err() -> PromptError {
+    PromptError::Io(io::Error::other("the prompt thread panicked"))
+}
+
+/// Counterpart to [`Prompter`] for types that implement [`TryFrom<String>`] instead of [`FromStr`], which converts each input with [`T::try_from()`](TryFrom::try_from).
+///
+/// When the conversion fails, the conversion error message is printed followed by the conversion error, e.g. `Please enter a username. (usernames can't contain spaces)`.
+#[must_use]
+pub struct TryFromPrompter<'a, T>
+where
+    T: TryFrom<String>,
+    T::Error: Display,
+{
+    /// The prompter whose conversion and validation rules are applied to each input.
+    prompter: Prompter<'a, T>,
+}
+
+impl<'a, T> TryFromPrompter<'a, T>
+where
+    T: TryFrom<String>,
+    T::Error: Display,
+{
+    /// Creates a `TryFromPrompter` with a `conversion_err_msg` to print (along with the conversion error) if the type conversion fails.
+    ///
+    /// Use the [`pred()`](TryFromPrompter::pred) function to add [`Predicate`]s to use when validating the value of the converted type.
+    pub fn new(conversion_err_msg: &'a str) -> Self {
+        Self::from_preds(conversion_err_msg, Vec::new())
+    }
+
+    /// Creates a `TryFromPrompter` with a `conversion_err_msg` to print (along with the conversion error) if the type conversion fails, and [`Predicate`]s to evaluate when an input is received.
+    pub fn from_preds(conversion_err_msg: &'a str, predicates: Vec<Predicate<'a, T>>) -> Self {
+        let conversion_fn = move |val: &str| T::try_from(val.to_string()).map_err(|err| format!("{conversion_err_msg} ({err})"));
+        Self {
+            prompter: Prompter::from_conversion_fn(conversion_err_msg, Box::new(conversion_fn)).with_preds(predicates),
+        }
+    }
+
+    /// Consumes the existing `TryFromPrompter` and returns a new `TryFromPrompter` that includes the new predicate.
+    pub fn pred(self, predicate: Predicate<'a, T>) -> Self {
+        Self {
+            prompter: self.prompter.pred(predicate),
+        }
+    }
+
+    /// Prompts the user for an input like [`Prompter::prompt()`].
+    ///
+    /// # Errors
+    /// Propogates the same errors as [`Prompter::prompt()`].
+    pub fn prompt(&self, msg: &str) -> PromptResult<T> {
+        self.prompter.prompt(msg)
+    }
+
+    /// Prompts the user for an input like [`Prompter::prompt_with_backend()`], reading the input from (and printing messages to) `backend`.
+    ///
+    /// # Errors
+    /// Propogates the same errors as [`Prompter::prompt_with_backend()`].
+    pub fn prompt_with_backend(&self, msg: &str, backend: &dyn TerminalBackend) -> PromptResult<T> {
+        self.prompter.prompt_with_backend(msg, backend)
+    }
+}
+
+/// Asynchronous counterpart to [`Prompter`], which reads input using [`tokio::io`] instead of blocking the thread.
+///
+/// Predicates are shared with [`Prompter`] and remain synchronous.
+#[cfg(feature = "tokio")]
+#[must_use]
+pub struct AsyncPrompter<'a, T>
+where
+    T: FromStr
+{
+    /// The prompter whose conversion and validation rules are applied to each input.
+    prompter: Prompter<'a, T>,
+}
+
+#[cfg(feature = "tokio")]
+impl<'a, T> AsyncPrompter<'a, T>
+    where T: FromStr
+{
+    /// Creates an `AsyncPrompter` with a `conversion_err_msg` to print if the type conversion fails.
+    ///
+    /// Use the [`pred()`](AsyncPrompter::pred) function to add [`Predicate`]s to use when validating the value of the converted type.
+    pub fn new(conversion_err_msg: &'a str) -> Self {
+        Self {
+            prompter: Prompter::new(conversion_err_msg),
+        }
+    }
+
+    /// Creates an `AsyncPrompter` with a `conversion_err_msg` to print if the type conversion fails, and [`Predicate`]s to evaluate when an input is received.
+    pub fn from_preds(conversion_err_msg: &'a str, predicates: Vec<Predicate<'a, T>>) -> Self {
+        Self {
+            prompter: Prompter::from_preds(conversion_err_msg, predicates),
+        }
+    }
+
+    /// Consumes the existing `AsyncPrompter` and returns a new `AsyncPrompter` that includes the new predicate.
+    pub fn pred(self, predicate: Predicate<'a, T>) -> Self {
+        Self {
+            prompter: self.prompter.pred(predicate),
+        }
+    }
+
+    /// Consumes the existing `AsyncPrompter` and returns a new `AsyncPrompter` that uses the styling of `theme`.
+    pub fn with_theme(self, theme: &Theme) -> Self {
+        Self {
+            prompter: self.prompter.with_theme(theme),
+        }
+    }
+
+    /// Consumes the existing `AsyncPrompter` and returns a new `AsyncPrompter` that gives up after `n` failed attempts.
+    pub fn max_attempts(self, n: usize) -> Self {
+        Self {
+            prompter: self.prompter.max_attempts(n),
+        }
+    }
+
+    /// Prompts the user for an input without blocking the async runtime.
+    /// This function will continue prompting if either the user's input cannot be converted to the desired type or if any of the predicates fail.
+    ///
+    /// # Errors
+    /// Propogates any I/O errors from reading the input (including an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when stdin is closed), and returns [`PromptError::MaxAttemptsExceeded`] once the number of failed attempts reaches the limit set by [`max_attempts()`](AsyncPrompter::max_attempts).
+    #[allow(clippy::future_not_send)] // predicates are not required to be `Send`, so neither is the future
+    pub async fn prompt(&self, msg: &str) -> PromptResult<T> {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+        static STDIN: OnceLock<tokio::sync::Mutex<BufReader<tokio::io::Stdin>>> = OnceLock::new();
+
+        let msg = self.prompter.display_msg(msg);
+        let stdin = STDIN.get_or_init(|| tokio::sync::Mutex::new(BufReader::new(tokio::io::stdin()))); // shared, so that buffered lines carry over to the next prompt
+        let mut stdout = tokio::io::stdout();
+        let mut failed_attempts = 0;
+        loop {
+            if let Some(max) = self.prompter.max_attempts {
+                if failed_attempts >= max {
+                    return Err(PromptError::MaxAttemptsExceeded);
                 }
-                Err(_) => {
-                    println!("Something went wrong with reading the input.");
+            }
+
+            if crate::is_interactive() {
+                stdout.write_all(msg.as_bytes()).await?;
+                stdout.flush().await?;
+            }
+
+            let mut val = String::new();
+            if stdin.lock().await.read_line(&mut val).await? == 0 {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stdin was closed").into());
+            }
+
+            match self.prompter.evaluate(&val) {
+                Ok(val) => return Ok(val),
+                Err(invalid_msg) => {
+                    stdout.write_all(format!("{}\n", self.prompter.invalid_style.apply_to(invalid_msg)).as_bytes()).await?;
+                    failed_attempts += 1;
                 }
             }
         }
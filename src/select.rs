@@ -1,76 +1,1444 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::env;
 use std::fmt::Display;
 use std::io;
+use std::thread;
+use std::time::Duration;
 
-use console::{Key, Term};
+use console::{style, Key, Term};
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::error::{PromptError, PromptResult};
+use crate::theme::{default_theme, Theme};
+use crate::testing::TerminalBackend;
+use crate::TermTarget;
+
+/// Boxed function called with the index and value of the option under the cursor whenever it changes (see [`Select::on_change`]).
+type ChangeFn<C> = Box<dyn Fn(usize, &C)>;
+
+/// Boxed function that returns the lines previewed below the options for the value of the option under the cursor (see [`Select::with_preview`]).
+pub type PreviewFn<C> = Box<dyn Fn(&C) -> Vec<String>>;
+
+/// Boxed function that renders the step indicator of a [`Select`], given the current step number and the total number of steps (see [`Select::step_format`]).
+type StepFormatFn = Box<dyn Fn(usize, usize) -> String>;
+
+/// Boxed function called when the "add" entry of a [`Select`] is chosen, which returns the value of the option to add (see [`Select::with_add_option`]).
+type AddFn<C> = Box<dyn Fn() -> PromptResult<C>>;
+
 /// Represents a single-select dialog.
 #[must_use]
-pub struct Select<C, D>
+#[allow(clippy::struct_excessive_bools)] // each flag is an independent builder option
+pub struct Select<C, D>
+where
+    C: Clone,
+    D: Display,
+{
+    /// The index of the default option (e.g. 0 represents the first option in the `options` vector).
+    default_index: usize,
+    /// The number of lines that separates the prompt from other text.
+    padding: usize,
+    /// Determines if the selected and unselected answers should be aligned.
+    is_aligned: bool,
+    /// The prefix to print ahead of the currently selected item.
+    prefix: String,
+    /// Determines whether to clear the prompt after an answer is given.
+    clear_after_response: bool,
+    /// The keys used to navigate and confirm (or cancel) the selection.
+    keymap: KeyMap,
+    /// The maximum number of options rendered at once, if any. The rendered window scrolls to follow the cursor.
+    visible_rows: Option<usize>,
+    /// Determines whether a scrollbar is rendered when some options fall outside of the rendered window.
+    has_scrollbar: bool,
+    /// Determines whether the options are rendered on a single line (see [`Select::inline`]).
+    is_inline: bool,
+    /// Determines whether the options are numbered, so that digit keys jump to them (see [`Select::numbered`]).
+    is_numbered: bool,
+    /// The key that toggles the options between insertion order and alphabetical order, if any (see [`Select::sortable`]).
+    sort_key: Option<Key>,
+    /// Determines whether rendered lines wider than the terminal are truncated (with an ellipsis) so that they do not wrap.
+    truncates: bool,
+    /// Determines whether the option under the cursor flashes once when the selection is first rendered (see [`Select::with_entry_animation`]).
+    has_entry_animation: bool,
+    /// How typing filters the options, if it does (see [`Select::searchable`] and [`Select::with_search`]).
+    search_mode: Option<SearchMode>,
+    /// The message rendered when no options match the search query, if not the default.
+    no_results_msg: Option<D>,
+    /// The headers of each option group (see [`Select::new_grouped`]), paired with the index of the first option in the group.
+    group_headers: Vec<(usize, D)>,
+    /// The indices of the options followed by a separator line (see [`Select::add_separator_after`]).
+    separators: Vec<usize>,
+    /// The options that the selection displays when prompting, which grow as options are added through the "add" entry.
+    options: RefCell<Vec<SelectOpt<C, D>>>,
+    /// The "add" entry rendered below the options, if any.
+    add_option: Option<AddEntry<C, D>>,
+    /// Called whenever the option under the cursor changes, if set.
+    on_change: Option<ChangeFn<C>>,
+    /// The number of lines reserved below the options for the output of `on_change` or `preview_fn`.
+    preview_lines: usize,
+    /// Returns the lines rendered in the preview area for the option under the cursor, if set.
+    preview_fn: Option<PreviewFn<C>>,
+    /// The terminal stream that the selection is rendered to.
+    target: TermTarget,
+    /// The current step number and the total number of steps, rendered above the prompt message if set (see [`Select::with_step`]).
+    step: Option<(usize, usize)>,
+    /// Renders the step indicator, if not the default `[current/total]`.
+    step_format: Option<StepFormatFn>,
+}
+
+/// The key bindings used to navigate a [`Select`].
+///
+/// Each action may be bound to any number of keys. The default bindings use the arrow keys to move, enter to confirm, and escape to cancel.
+/// Regardless of the bindings, home and end jump to the first and last option, and page up and page down move by a full window (see [`Select::visible_rows`]).
+///
+/// Note that when the selection is [`searchable()`](Select::searchable), character keys are typed into the search query instead of being matched against the bindings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyMap {
+    /// The keys that move the cursor to the previous option.
+    pub up: Vec<Key>,
+    /// The keys that move the cursor to the next option.
+    pub down: Vec<Key>,
+    /// The keys that confirm the option under the cursor.
+    pub confirm: Vec<Key>,
+    /// The keys that cancel the selection, if any. Only used by [`Select::prompt_cancellable`].
+    pub cancel: Vec<Key>,
+}
+
+impl KeyMap {
+    /// Returns vim-style bindings: `k` and `j` move up and down, `l` (or enter) confirms, and `q` cancels.
+    ///
+    /// ```
+    /// use prediput::select::{KeyMap, Select, SelectOpt};
+    ///
+    /// let sel = Select::new("> ", vec![SelectOpt::new("Yes", None, true), SelectOpt::new("No", None, false)])
+    ///     .keymap(KeyMap::vim());
+    /// ```
+    #[must_use]
+    pub fn vim() -> Self {
+        Self {
+            up: vec![Key::Char('k')],
+            down: vec![Key::Char('j')],
+            confirm: vec![Key::Char('l'), Key::Enter],
+            cancel: vec![Key::Char('q')],
+        }
+    }
+
+    /// Returns game-style bindings: `w` and `s` move up and down, `d` (or enter) confirms, and `q` cancels.
+    ///
+    /// ```
+    /// use prediput::select::{KeyMap, Select, SelectOpt};
+    ///
+    /// let sel = Select::new("> ", vec![SelectOpt::new("Fight", None, 1), SelectOpt::new("Run", None, 2)])
+    ///     .keymap(KeyMap::wasd());
+    /// ```
+    #[must_use]
+    pub fn wasd() -> Self {
+        Self {
+            up: vec![Key::Char('w')],
+            down: vec![Key::Char('s')],
+            confirm: vec![Key::Char('d'), Key::Enter],
+            cancel: vec![Key::Char('q')],
+        }
+    }
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            up: vec![Key::ArrowUp],
+            down: vec![Key::ArrowDown],
+            confirm: vec![Key::Enter],
+            cancel: vec![Key::Escape],
+        }
+    }
+}
+
+/// Represents a single option in a [`Select`](Select).
+#[must_use]
+pub struct SelectOpt<C, D>
+where
+    C: Clone,
+    D: Display,
+{
+    /// The text to render by default.
+    pub display_text: D,
+    /// The text to render when the option is currently selected.
+    pub selected_text: Option<D>,
+    /// The value that the option represents. This will be returned by the prompter.
+    pub value: C,
+    /// Determines whether the option is rendered greyed-out and cannot be selected.
+    pub disabled: bool,
+    /// The reason the option is disabled, if any, which is rendered (dimmed) after the display text.
+    pub disabled_reason: Option<D>,
+}
+
+impl<C, D> SelectOpt<C, D>
+where
+    C: Clone,
+    D: Display
+{
+    /// Constructs a new option.
+    /// Prompts will return the `value` passed into this struct.
+    pub fn new(display_text: D, selected_text: Option<D>, value: C) -> Self {
+        Self {
+            display_text,
+            selected_text,
+            value,
+            disabled: false,
+            disabled_reason: None,
+        }
+    }
+
+    /// Constructs a new disabled option, with an optional `reason` that is rendered (dimmed) after the display text.
+    pub fn new_disabled(display_text: D, reason: Option<D>, value: C) -> Self {
+        Self {
+            disabled: true,
+            disabled_reason: reason,
+            ..Self::new(display_text, None, value)
+        }
+    }
+
+    /// Marks the option as disabled, so that it is rendered greyed-out and skipped over by the cursor.
+    /// Consumes the `SelectOpt` and returns a transformed one.
+    pub fn disable(self) -> Self {
+        Self {
+            disabled: true,
+            ..self
+        }
+    }
+
+    /// Marks the option as disabled like [`disable()`](SelectOpt::disable), rendering `reason` (dimmed) after the display text.
+    /// Consumes the `SelectOpt` and returns a transformed one.
+    pub fn disable_with_reason(self, reason: D) -> Self {
+        Self {
+            disabled: true,
+            disabled_reason: Some(reason),
+            ..self
+        }
+    }
+
+    /// Returns the greyed-out form of the display text for a disabled option, followed by the reason it is disabled (if any).
+    fn disabled_text(&self) -> String {
+        let text = style(self.display_text.to_string().decolored()).dim().to_string();
+        match &self.disabled_reason {
+            Some(reason) => format!("{text}{}", style(format!("  ({reason})")).dim()),
+            None => text,
+        }
+    }
+}
+
+/// Represents a group of options under a shared header in a [`Select`] (see [`Select::new_grouped`]).
+#[must_use]
+pub struct SelectGroup<C, D>
+where
+    C: Clone,
+    D: Display,
+{
+    /// The text rendered above the group's options.
+    pub header: D,
+    /// The options in the group.
+    pub options: Vec<SelectOpt<C, D>>,
+}
+
+impl<C, D> SelectGroup<C, D>
+where
+    C: Clone,
+    D: Display,
+{
+    /// Constructs a new group of options.
+    pub fn new(header: D, options: Vec<SelectOpt<C, D>>) -> Self {
+        Self {
+            header,
+            options,
+        }
+    }
+}
+
+impl<C, D> Select<C, D>
+where
+    C: Clone,
+    D: Display,
+{
+    /// Creates a new selection with a collection of tuples containing the following items:
+    /// - the text to print
+    /// - the thing that maps to that text (i.e. if that text is selected, the corresponding thing is returned by the [`prompt()`](Select::prompt) function).
+    ///
+    /// The first option that is not disabled is initially selected.
+    ///
+    /// The padding and alignment are taken from the default [`Theme`] (see [`set_default_theme()`](crate::set_default_theme)).
+    pub fn new(selected_prefix: D, options: Vec<SelectOpt<C, D>>) -> Self {
+        let theme = default_theme();
+
+        Self {
+            default_index: options.iter().position(|opt| !opt.disabled).unwrap_or(0),
+            padding: theme.padding,
+            is_aligned: theme.select_aligned,
+            prefix: selected_prefix.to_string(),
+            clear_after_response: false,
+            keymap: KeyMap::default(),
+            visible_rows: None,
+            has_scrollbar: true,
+            is_inline: false,
+            is_numbered: false,
+            has_entry_animation: false,
+            truncates: true,
+            sort_key: None,
+            search_mode: None,
+            no_results_msg: None,
+            group_headers: Vec::new(),
+            separators: Vec::new(),
+            options: RefCell::new(options),
+            add_option: None,
+            on_change: None,
+            preview_lines: 0,
+            preview_fn: None,
+            target: TermTarget::Stdout,
+            step: None,
+            step_format: None,
+        }
+    }
+
+    /// Creates a new selection from groups of options.
+    /// Each group's header is rendered (emboldened, with a separator above it) ahead of the group's options, and cannot be selected.
+    pub fn new_grouped(selected_prefix: D, groups: Vec<SelectGroup<C, D>>) -> Self {
+        let mut group_headers = Vec::with_capacity(groups.len());
+        let mut options = Vec::new();
+        for SelectGroup { header, options: group_options } in groups {
+            group_headers.push((options.len(), header));
+            options.extend(group_options);
+        }
+
+        Self {
+            group_headers,
+            ..Self::new(selected_prefix, options)
+        }
+    }
+
+    /// Adds an option to the selection; consumes the calling instance and returns the transformed one.
+    pub fn opt(self, select_opt: SelectOpt<C, D>) -> Self {
+        let mut options_vec = self.options.into_inner();
+        options_vec.push(select_opt);
+        Self {
+            options: RefCell::new(options_vec),
+            ..self
+        }
+    }
+
+    /// Sets the padding, or the number of lines that separates the selection from the text above it.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn padding(self, num_lines: usize) -> Self {
+        Self {
+            padding: num_lines,
+            ..self
+        }
+    }
+
+    /// Sets the prefix for the selected item.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn prefix(self, selected_prefix: D) -> Self {
+        Self {
+            prefix: selected_prefix.to_string(),
+            ..self
+        }
+    }
+
+    /// Applies the prefix, alignment, and padding of `theme`; builder calls made afterwards override the theme's values.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn with_theme(self, theme: &Theme) -> Self {
+        Self {
+            prefix: theme.select_prefix.clone(),
+            is_aligned: theme.select_aligned,
+            padding: theme.padding,
+            ..self
+        }
+    }
+
+    /// Makes the options aligned together, instead of having to manually indent them in the selection's options. Note that added spaces in the default text may cause unexpected spacing.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn aligned(self) -> Self {
+        Self {
+            is_aligned: true,
+            ..self
+        }
+    }
+
+    /// Sets whether the prompt should be cleared after a response is given.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn clear_after(self) -> Self {
+        Self {
+            clear_after_response: true,
+            ..self
+        }
+    }
+
+    /// Sets the default option (the thing that's initially selected).
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn default_opt(self, default_index: usize) -> Self {
+        Self {
+            default_index,
+            ..self
+        }
+    }
+
+    /// Sets the key bindings used to navigate and confirm (or cancel) the selection.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn keymap(self, keymap: KeyMap) -> Self {
+        Self {
+            keymap,
+            ..self
+        }
+    }
+
+    /// Adds `j` (down) and `k` (up) to the keymap alongside the existing keys, as a fallback for terminals and SSH sessions that don't reliably send arrow keys.
+    /// Enter is also added as a confirmation key if the keymap lacks it. While [searching](Select::searchable), typed characters go to the query instead.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn arrow_fallback(self) -> Self {
+        let with_key = |mut keys: Vec<Key>, key: Key| -> Vec<Key> {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+            keys
+        };
+
+        let KeyMap { up, down, confirm, cancel } = self.keymap;
+        Self {
+            keymap: KeyMap { up: with_key(up, Key::Char('k')), down: with_key(down, Key::Char('j')), confirm: with_key(confirm, Key::Enter), cancel },
+            ..self
+        }
+    }
+
+    /// Calls `change_fn` with the index and value of the option under the cursor whenever it changes while prompting (including when the options are first rendered).
+    /// Anything `change_fn` prints is written to the lines reserved with [`preview_lines()`](Select::preview_lines), and the cursor is restored afterwards.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn on_change(self, change_fn: impl Fn(usize, &C) + 'static) -> Self {
+        Self {
+            on_change: Some(Box::new(change_fn)),
+            ..self
+        }
+    }
+
+    /// Renders an "add" entry labelled `label` below the options. Choosing it calls `add_fn`, which may prompt for a new value;
+    /// the value is then added as an option (with its [`Display`] form as the display text), the cursor moves onto it, and the selection continues.
+    ///
+    /// Anything `add_fn` prints is left above the options. When not [interactive](crate::is_interactive), answering with the label adds an option the same way.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn with_add_option(self, label: D, add_fn: Box<dyn Fn() -> PromptResult<C>>) -> Self
+    where
+        C: Display + 'static,
+        D: From<String> + 'static,
+    {
+        Self {
+            add_option: Some(AddEntry { label, add_fn, display_fn: |value| D::from(value.to_string()) }),
+            ..self
+        }
+    }
+
+    /// Reserves `n` lines below the options for the output of the [`on_change()`](Select::on_change) callback, such as a description of the option under the cursor.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn preview_lines(self, n: usize) -> Self {
+        Self {
+            preview_lines: n,
+            ..self
+        }
+    }
+
+    /// Reserves `height` lines below the options for a preview of the option under the cursor, which are filled with the lines `preview_fn` returns for the option's value
+    /// (truncated to `height`, with any remaining lines left blank) whenever the cursor moves.
+    ///
+    /// Unlike [`on_change()`](Select::on_change), `preview_fn` does not need to position the cursor itself; this replaces the number of lines set with [`preview_lines()`](Select::preview_lines).
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn with_preview(self, preview_fn: PreviewFn<C>, height: usize) -> Self {
+        Self {
+            preview_fn: Some(preview_fn),
+            preview_lines: height,
+            ..self
+        }
+    }
+
+    /// Renders the selection to stderr instead of stdout, which keeps it out of an application's (possibly piped) output.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn to_stderr(self) -> Self {
+        Self {
+            target: TermTarget::Stderr,
+            ..self
+        }
+    }
+
+    /// Renders a step indicator (`[current/total]`, by default) on a line above the prompt message, to show progress through a multi-step flow.
+    /// The indicator is purely decorative; see [`step_format()`](Select::step_format) to change how it is rendered.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn with_step(self, current: usize, total: usize) -> Self {
+        Self {
+            step: Some((current, total)),
+            ..self
+        }
+    }
+
+    /// Renders the step indicator set with [`with_step()`](Select::with_step) with `format_fn`, which is given the current step number and the total number of steps (e.g. to render `Step 2 of 5`).
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn step_format(self, format_fn: impl Fn(usize, usize) -> String + 'static) -> Self {
+        Self {
+            step_format: Some(Box::new(format_fn)),
+            ..self
+        }
+    }
+
+    /// Limits the number of options rendered at once to `n`; the rendered window scrolls as the cursor moves past its boundaries.
+    /// Page up and page down move the cursor by a full window, and a scrollbar is rendered to the right of the options (see [`no_scrollbar()`](Select::no_scrollbar)).
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn visible_rows(self, n: usize) -> Self {
+        Self {
+            visible_rows: Some(n),
+            ..self
+        }
+    }
+
+    /// Disables the scrollbar that is otherwise rendered to the right of the options when [`visible_rows()`](Select::visible_rows) hides some of them.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn no_scrollbar(self) -> Self {
+        Self {
+            has_scrollbar: false,
+            ..self
+        }
+    }
+
+    /// Renders a separator (a dimmed horizontal rule as wide as the longest option) between the option at `index` and the next one, which cannot be selected.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn add_separator_after(self, index: usize) -> Self {
+        let mut separators = self.separators;
+        separators.push(index);
+        Self {
+            separators,
+            ..self
+        }
+    }
+
+    /// Renders a number ahead of each option (e.g. `1. Option A`), and lets the digit keys `1` to `9` jump to the option with that number.
+    /// If there are at most 9 options, pressing a digit also confirms the option, without waiting for enter.
+    ///
+    /// Digit keys are typed into the search query instead when the selection is [`searchable()`](Select::searchable).
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn numbered(self) -> Self {
+        Self {
+            is_numbered: true,
+            ..self
+        }
+    }
+
+    /// Lets the user press `s` to sort the rendered options alphabetically (by their decolored display text), and press it again to restore their original order.
+    /// The cursor stays on the same option, and group headers and separators are hidden while the options are sorted.
+    ///
+    /// Keys bound in the keymap (such as `s` in [`KeyMap::wasd()`]) take precedence, as does typing into the search query of a [`searchable()`](Select::searchable) selection.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn sortable(self) -> Self {
+        self.sortable_with_key(Key::Char('s'))
+    }
+
+    /// Makes the selection [`sortable()`](Select::sortable), but toggles the sort order with `key` instead of `s`.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn sortable_with_key(self, key: Key) -> Self {
+        Self {
+            sort_key: Some(key),
+            ..self
+        }
+    }
+
+    /// Renders lines wider than the terminal in full, instead of truncating them with an ellipsis (`…`).
+    /// Only use this if every option's text is guaranteed to fit, since a wrapped line leaves rendering artifacts behind when the selection is redrawn.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn no_truncate(self) -> Self {
+        Self {
+            truncates: false,
+            ..self
+        }
+    }
+
+    /// Briefly highlights (in reversed colors) the option under the cursor when the selection is first rendered, drawing attention to it before any keys are read.
+    /// The animation is skipped when not [interactive](crate::is_interactive) or when the `TERM` environment variable is `dumb`.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn with_entry_animation(self) -> Self {
+        Self {
+            has_entry_animation: true,
+            ..self
+        }
+    }
+
+    /// Renders every option on a single line (separated by spaces) instead of one option per line, which suits short option sets like "Yes / No".
+    /// The left and right arrow keys take the place of the up and down arrow keys in the keymap, and group headers and [`visible_rows()`](Select::visible_rows) are ignored.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn inline(self) -> Self {
+        let horizontal = |keys: Vec<Key>| -> Vec<Key> {
+            keys.into_iter()
+                .map(|key| match key {
+                    Key::ArrowUp => Key::ArrowLeft,
+                    Key::ArrowDown => Key::ArrowRight,
+                    key => key,
+                })
+                .collect()
+        };
+
+        let KeyMap { up, down, confirm, cancel } = self.keymap;
+        Self {
+            is_inline: true,
+            keymap: KeyMap { up: horizontal(up), down: horizontal(down), confirm, cancel },
+            ..self
+        }
+    }
+
+    /// Renders a search box above the options; typing filters the options down to those whose display text contains the query as a subsequence (fuzzy match).
+    /// Backspace removes the last character from the query, and the matching characters of each option are emboldened while a query is active.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn searchable(self) -> Self {
+        Self {
+            search_mode: Some(SearchMode::Fuzzy),
+            ..self
+        }
+    }
+
+    /// Renders a search box above the options like [`searchable()`](Select::searchable), but filters the options down to those whose display text contains the query as a case-insensitive substring.
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn with_search(self) -> Self {
+        Self {
+            search_mode: Some(SearchMode::Substring),
+            ..self
+        }
+    }
+
+    /// Sets the message rendered when no options match the search query (see [`searchable()`](Select::searchable)).
+    /// Consumes the `Select` and returns a transformed one.
+    pub fn no_results_msg(self, msg: D) -> Self {
+        Self {
+            no_results_msg: Some(msg),
+            ..self
+        }
+    }
+
+    /// Prompts the user for an input by printing `msg` (to stdout, unless [`to_stderr()`](Select::to_stderr) is set).
+    /// This function will print the textual part of all options, and return the corresponding value represented by it (i.e. a `value` -- which conforms to type `C`).
+    ///
+    /// Typing a character that is not bound to another key jumps to the next option whose (decolored) display text starts with it, case-insensitively,
+    /// so pressing the same character again cycles through the matching options. This is disabled when the selection is [`searchable()`](Select::searchable).
+    ///
+    /// # Errors
+    /// Propogates the following errors:
+    /// - [`Term::write_line`]
+    /// - [`Term::read_key`]
+    /// - [`Term::hide_cursor`]
+    /// - [`Term::show_cursor`]
+    /// - [`Term::clear_last_lines`]
+    ///
+    /// Returns [`PromptError::Cancelled`] if Ctrl+C is pressed,
+    /// or [`PromptError::InvalidDefault`] before anything is rendered if the default option index (see [`default_opt()`](Select::default_opt)) is out of bounds or the default option is disabled.
+    pub fn prompt(&self, msg: D) -> PromptResult<C> {
+        self.prompt_indexed(msg).map(|(_, value)| value)
+    }
+
+    /// Prompts the user for an input like [`prompt()`](Select::prompt), but also returns the (zero-based) index of the selected option.
+    ///
+    /// # Errors
+    /// Propogates the same errors as [`prompt()`](Select::prompt).
+    #[allow(clippy::missing_panics_doc)] // a non-cancellable selection is never cancelled
+    pub fn prompt_indexed(&self, msg: D) -> PromptResult<(usize, C)> {
+        Ok(self.run(msg, false)?.expect("non-cancellable selection was unexpectedly cancelled"))
+    }
+
+    /// Prompts the user for an input like [`prompt()`](Select::prompt), but returns `None` if the keymap's cancel key (escape, by default) is pressed.
+    /// When cancelled, the rendered options and prompt are cleared, regardless of [`clear_after()`](Select::clear_after).
+    ///
+    /// # Errors
+    /// Propogates the same errors as [`prompt()`](Select::prompt).
+    pub fn prompt_cancellable(&self, msg: D) -> PromptResult<Option<C>> {
+        Ok(self.run(msg, true)?.map(|(_, value)| value))
+    }
+
+    /// Prompts the user for an input like [`prompt()`](Select::prompt), then asks them to confirm the selection with [`confirm_ternary()`](crate::confirm_ternary), printing `confirm_msg`.
+    /// Returns `Some` value once a selection is confirmed (`'y'`), shows the selection again if it is declined (`'n'`), or returns `None` if escape is pressed.
+    ///
+    /// Useful ahead of irreversible actions, such as deleting a file.
+    ///
+    /// # Errors
+    /// Propogates the same errors as [`prompt()`](Select::prompt) and [`confirm_ternary()`](crate::confirm_ternary).
+    pub fn prompt_with_confirmation(&self, msg: D, confirm_msg: D) -> PromptResult<Option<C>>
+    where
+        D: Clone,
+    {
+        let confirm_msg = confirm_msg.to_string();
+        loop {
+            let value = self.prompt(msg.clone())?;
+            match crate::confirm_ternary(&confirm_msg, true)? {
+                Some(true) => return Ok(Some(value)),
+                Some(false) => {} // show the selection again
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Prompts the user for an input like [`prompt()`](Select::prompt), but prints the options to (and reads a line from) `backend` instead of reading keystrokes from the terminal.
+    /// The line is read as an option's display text or (zero-based) index, as when not [interactive](crate::is_interactive).
+    ///
+    /// This is mostly useful for testing, with a [`MockTerminal`](crate::testing::MockTerminal).
+    ///
+    /// # Errors
+    /// Propogates any I/O errors from `backend`, and returns [`PromptError::ValidationFailed`] if the line does not match an (enabled) option.
+    /// Also returns [`PromptError::InvalidDefault`] if the default option index (see [`default_opt()`](Select::default_opt)) is out of bounds or the default option is disabled.
+    pub fn prompt_with_backend(&self, msg: D, backend: &dyn TerminalBackend) -> PromptResult<C> {
+        self.validate()?;
+        if let Some(step_header) = self.step_header() {
+            backend.print_line(&step_header)?;
+        }
+        backend.print_line(&msg.to_string())?;
+        loop { // the options are printed again after one is added
+            let state = self.initial_state();
+            for line in self.render_lines(&state, None).into_iter().chain(self.preview_content(state.matches.get(state.cursor).copied())) {
+                backend.print_line(&line)?;
+            }
+
+            let answer = backend.input("")?;
+            if self.is_add_answer(&answer) {
+                self.push_added_option()?;
+                continue;
+            }
+
+            let options = self.options.borrow();
+            return find_option(&options, &answer)
+                .map(|i| options[i].value.clone())
+                .ok_or_else(|| crate::unrecognized_answer(&answer));
+        }
+    }
+
+    /// Checks the invariants configured through the builder methods, returning [`PromptError::InvalidDefault`] describing the first one that does not hold.
+    fn validate(&self) -> PromptResult<()> {
+        let entry_count = self.entry_count();
+        if self.default_index >= entry_count {
+            let msg = format!("the default option index ({}) is out of bounds for a selection with {entry_count} options", self.default_index);
+            return Err(PromptError::InvalidDefault(msg));
+        }
+        if self.is_disabled(self.default_index) { // the cursor could never rest on it
+            return Err(PromptError::InvalidDefault(format!("the default option (at index {}) is disabled", self.default_index)));
+        }
+        Ok(())
+    }
+
+    /// Returns the step indicator rendered above the prompt message, if a step is set.
+    fn step_header(&self) -> Option<String> {
+        let (current, total) = self.step?;
+        Some(self.step_format.as_ref().map_or_else(|| format!("[{current}/{total}]"), |format_fn| format_fn(current, total)))
+    }
+
+    /// Returns the number of entries that can be rendered, which includes the "add" entry (if any) after the options.
+    fn entry_count(&self) -> usize {
+        self.options.borrow().len() + usize::from(self.add_option.is_some())
+    }
+
+    /// Returns the display text of the entry at index `i`, which is the label of the "add" entry if `i` is past the options.
+    fn display_text(&self, i: usize) -> String {
+        self.options.borrow().get(i).map_or_else(
+            || self.add_option.as_ref().map(|entry| entry.label.to_string()).unwrap_or_default(),
+            |opt| opt.display_text.to_string(),
+        )
+    }
+
+    /// Returns whether the option at index `i` is disabled (the "add" entry never is).
+    fn is_disabled(&self, i: usize) -> bool {
+        self.options.borrow().get(i).is_some_and(|opt| opt.disabled)
+    }
+
+    /// Returns whether `answer` (read as a line) matches the label of the "add" entry, case-insensitively.
+    fn is_add_answer(&self, answer: &str) -> bool {
+        self.add_option.as_ref().is_some_and(|entry| entry.label.to_string().decolored().trim().to_lowercase() == answer.to_lowercase())
+    }
+
+    /// Calls the function of the "add" entry and adds the option it returns, returning the option's index.
+    ///
+    /// # Panics
+    /// Panics if the selection has no "add" entry.
+    fn push_added_option(&self) -> PromptResult<usize> {
+        let entry = self.add_option.as_ref().expect("the selection has no \"add\" entry");
+        let value = (entry.add_fn)()?;
+        let mut options = self.options.borrow_mut();
+        options.push(SelectOpt::new((entry.display_fn)(&value), None, value));
+        Ok(options.len() - 1)
+    }
+
+    /// Returns the indices of the options whose (decolored) display text matches `query` (see [`SearchMode`]).
+    fn filtered_indices(&self, query: &str) -> Vec<usize> {
+        (0..self.entry_count())
+            .filter(|&i| self.match_positions(&self.display_text(i).decolored(), query).is_some())
+            .collect()
+    }
+
+    /// Returns the indices of the options that match the query of `state`, in display order: sorted by their (decolored) display text if `state` is sorted, and in insertion order otherwise.
+    /// The "add" entry (if any) always comes last.
+    fn matching_indices(&self, state: &SelectState) -> Vec<usize> {
+        let mut matches = self.filtered_indices(&state.query);
+        if state.is_sorted {
+            let option_count = self.options.borrow().len();
+            matches.sort_by_cached_key(|&i| (i >= option_count, self.display_text(i).decolored()));
+        }
+        matches
+    }
+
+    /// Returns the character positions in `text` that match `query` under the selection's search mode, or `None` if `text` does not match.
+    fn match_positions(&self, text: &str, query: &str) -> Option<Vec<usize>> {
+        match self.search_mode {
+            Some(SearchMode::Substring) => substring_match(text, query),
+            _ => fuzzy_match(text, query),
+        }
+    }
+
+    /// Returns the position (into `matches`) of the first option that is not disabled, searching from `start` forward (or backward, if not `forward`) and wrapping around.
+    /// Returns `start` if every option is disabled.
+    fn nearest_enabled(&self, matches: &[usize], start: usize, forward: bool) -> usize {
+        let len = matches.len();
+        (0..len)
+            .map(|step| if forward { (start + step) % len } else { (start + len - step) % len })
+            .find(|&pos| !self.is_disabled(matches[pos]))
+            .unwrap_or(start)
+    }
+
+    /// Returns the lines to render for the current state of the selection, truncated (with an ellipsis) to `columns` if set.
+    /// The options are truncated ahead of the scrollbar (if any), so that the scrollbar is always rendered.
+    fn render_lines(&self, state: &SelectState, columns: Option<usize>) -> Vec<String> {
+        let (mut lines, option_lines) = self.render_unscrolled_lines(state);
+        let has_scrollbar = self.has_scrollbar && !option_lines.is_empty() && option_lines.len() < state.matches.len();
+        if let Some(columns) = columns {
+            let option_columns = if has_scrollbar { columns.saturating_sub(SCROLLBAR_WIDTH) } else { columns };
+            for (i, line) in lines.iter_mut().enumerate() {
+                let max_width = if option_lines.contains(&i) { option_columns } else { columns };
+                *line = console::truncate_str(line, max_width, TRUNCATION_TAIL).into_owned();
+            }
+        }
+
+        if has_scrollbar {
+            add_scrollbar(&mut lines, &option_lines, state.cursor, state.matches.len());
+        }
+        lines
+    }
+
+    /// Returns the lines to render for the current state of the selection without a scrollbar, along with the indices (into the lines) of the rendered options.
+    fn render_unscrolled_lines(&self, state: &SelectState) -> (Vec<String>, Vec<usize>) {
+        let prefix_char_count = self.prefix.decolored().graphemes(true).count();
+        let mut lines = Vec::new();
+
+        if self.search_mode.is_some() {
+            lines.push(format!("{SEARCH_LABEL}{}{SEARCH_CURSOR}", state.query));
+        }
+
+        if state.matches.is_empty() {
+            lines.push(self.no_results_msg.as_ref().map_or_else(|| NO_RESULTS_MSG.to_string(), ToString::to_string));
+            return (lines, Vec::new());
+        }
+
+        if self.is_inline {
+            let options = state.matches.iter().enumerate().map(|(pos, &i)| self.render_option(state, pos, i, prefix_char_count));
+            lines.push(options.collect::<Vec<_>>().join(INLINE_SEPARATOR));
+            return (lines, Vec::new());
+        }
+
+        let window_len = self.window_len(state.matches.len());
+        let mut option_lines = Vec::with_capacity(window_len); // the indices (into `lines`) of the rendered options
+        let mut rendered_group = None;
+        let mut prev_rendered = None;
+        for (pos, &i) in state.matches.iter().enumerate().skip(state.view_offset).take(window_len)
+        {
+            // render a separator if one follows the option rendered before this one (or any options hidden between them)
+            if prev_rendered.is_some_and(|prev| self.separators.iter().any(|s| (prev..i).contains(s))) && !state.is_sorted {
+                lines.push(style(GROUP_SEPARATOR.repeat(self.max_option_width())).dim().to_string());
+            }
+            prev_rendered = Some(i);
+
+            // render the group's header ahead of the first of its options that is rendered
+            let group = self.group_headers.iter().rposition(|(start, _)| *start <= i).filter(|_| !state.is_sorted);
+            if let Some(group) = group.filter(|&group| Some(group) != rendered_group) {
+                let header = self.group_headers[group].1.to_string();
+                if rendered_group.is_some() {
+                    lines.push(style(GROUP_SEPARATOR.repeat(header.decolored().graphemes(true).count())).dim().to_string());
+                }
+                lines.push(style(header).bold().to_string());
+                rendered_group = Some(group);
+            }
+
+            option_lines.push(lines.len());
+            lines.push(self.render_option(state, pos, i, prefix_char_count));
+        }
+        (lines, option_lines)
+    }
+
+    /// Returns the width (in graphemes) of the longest (decolored) display text among the options.
+    fn max_option_width(&self) -> usize {
+        (0..self.entry_count()).map(|i| self.display_text(i).decolored().graphemes(true).count()).max().unwrap_or(0)
+    }
+
+    /// Returns the rendered form of the option at index `i` (into the selection's options), which is at position `pos` (into the matches of `state`).
+    fn render_option(&self, state: &SelectState, pos: usize, i: usize, prefix_char_count: usize) -> String {
+        let options = self.options.borrow();
+        let select_opt = options.get(i); // `None` for the "add" entry
+        let selected_text = select_opt.and_then(|opt| opt.selected_text.as_ref());
+        let display_text = if self.is_disabled(i) {
+            options[i].disabled_text()
+        } else if state.query.is_empty() {
+            self.display_text(i)
+        } else {
+            let display_text = self.display_text(i).decolored();
+            let positions = self.match_positions(&display_text, &state.query).unwrap_or_default();
+            highlighted(&display_text, &positions)
+        };
+        let number = self.number_prefix(i, options.len());
+
+        let flashed = |rendered: String| if state.is_flashing { style(rendered).reverse().to_string() } else { rendered };
+
+        match (pos == state.cursor, selected_text)
+        {
+            (true, None) => flashed(format!("{}{number}{}", self.prefix, display_text)),
+            (true, Some(sel_str)) => flashed(format!("{}{number}{}", self.prefix, sel_str)),
+            _ => {
+                let display_text = format!("{number}{display_text}");
+                if self.is_aligned {
+                    format!("{}{}", " ".repeat(prefix_char_count), display_text)
+                } else {
+                    display_text
+                }
+            }
+        }
+    }
+
+    /// Returns the number rendered ahead of the option at index `i` (right-aligned among `option_count` options), or an empty string if the selection is not numbered.
+    /// The "add" entry is padded to line up with the numbered options instead.
+    fn number_prefix(&self, i: usize, option_count: usize) -> String {
+        if !self.is_numbered {
+            return String::new();
+        }
+        let width = option_count.to_string().len();
+        if i < option_count { format!("{:>width$}. ", i + 1) } else { " ".repeat(width + 2) }
+    }
+
+    /// Returns the number of option lines rendered at once when `match_count` options match the search query.
+    fn window_len(&self, match_count: usize) -> usize {
+        if self.is_inline {
+            return match_count; // every option is rendered on the one line
+        }
+        self.visible_rows.map_or(match_count, |rows| rows.min(match_count))
+    }
+
+    /// Returns the state of the selection before any keys are pressed.
+    fn initial_state(&self) -> SelectState {
+        let mut state = SelectState {
+            query: String::new(),
+            matches: self.filtered_indices(""),
+            cursor: self.default_index,
+            view_offset: 0,
+            is_flashing: false,
+            is_sorted: false,
+        };
+        state.view_offset = scrolled_offset(state.cursor, 0, self.window_len(state.matches.len()));
+        state
+    }
+
+    /// Updates the state of the selection in response to `key`, returning whether the selection was confirmed or cancelled (if `cancellable`).
+    fn handle_key(&self, state: &mut SelectState, key: &Key, cancellable: bool) -> KeyOutcome<C> {
+        match key {
+            Key::Char(c) if self.search_mode.is_some() && !c.is_control() => {
+                state.query.push(*c);
+                state.matches = self.matching_indices(state);
+                state.cursor = self.nearest_enabled(&state.matches, 0, true);
+                state.view_offset = 0;
+                return KeyOutcome::Continue;
+            }
+            Key::Backspace if self.search_mode.is_some() => {
+                state.query.pop();
+                state.matches = self.matching_indices(state);
+                state.cursor = self.nearest_enabled(&state.matches, 0, true);
+                state.view_offset = 0;
+                return KeyOutcome::Continue;
+            }
+            _ => {}
+        }
+
+        if cancellable && self.keymap.cancel.contains(key) {
+            return KeyOutcome::Cancelled;
+        }
+
+        let match_count = state.matches.len();
+        if match_count == 0 {
+            return KeyOutcome::Continue; // there is nothing to navigate or confirm
+        }
+
+        let window_len = self.window_len(match_count);
+        if let Some(digit) = self.number_key(key) {
+            let option_count = self.options.borrow().len();
+            let target = digit - 1;
+            if let Some(pos) = state.matches.iter().position(|&i| i == target).filter(|_| !self.is_disabled(target)) {
+                state.cursor = pos;
+                if option_count <= 9 {
+                    return KeyOutcome::Confirmed(target, self.options.borrow()[target].value.clone());
+                }
+            }
+        } else if self.keymap.up.contains(key) {
+            let prev = if state.cursor == 0 { match_count - 1 } else { state.cursor - 1 };
+            state.cursor = self.nearest_enabled(&state.matches, prev, false);
+        } else if self.keymap.down.contains(key) {
+            state.cursor = self.nearest_enabled(&state.matches, (state.cursor + 1) % match_count, true);
+        } else if *key == Key::PageUp {
+            state.cursor = self.nearest_enabled(&state.matches, state.cursor.saturating_sub(window_len), true);
+        } else if *key == Key::PageDown {
+            state.cursor = self.nearest_enabled(&state.matches, (state.cursor + window_len).min(match_count - 1), false);
+        } else if *key == Key::Home {
+            state.cursor = self.nearest_enabled(&state.matches, 0, true);
+        } else if *key == Key::End {
+            state.cursor = self.nearest_enabled(&state.matches, match_count - 1, false);
+        } else if self.keymap.confirm.contains(key) {
+            let index = state.matches.get(state.cursor).copied().expect("unexpectedly failed to get selected item");
+            let options = self.options.borrow();
+            let Some(select_opt) = options.get(index) else {
+                return KeyOutcome::Add; // only the "add" entry follows the options
+            };
+
+            if !select_opt.disabled { // a disabled option is only reachable when every option is disabled
+                return KeyOutcome::Confirmed(index, select_opt.value.clone());
+            }
+        } else if self.sort_key.as_ref() == Some(key) {
+            let selected = state.matches[state.cursor];
+            state.is_sorted = !state.is_sorted;
+            state.matches = self.matching_indices(state);
+            state.cursor = state.matches.iter().position(|&i| i == selected).unwrap_or(0);
+        } else if let Key::Char(c) = key {
+            if let Some(pos) = self.jump_target(state, *c) {
+                state.cursor = pos;
+            }
+        }
+
+        // scroll the window (if needed) so that the selected item is visible
+        state.view_offset = scrolled_offset(state.cursor, state.view_offset, window_len);
+        KeyOutcome::Continue
+    }
+
+    /// Returns the position (in `state.matches`) of the first enabled option after the cursor whose decolored display text starts with `c` (case-insensitively), wrapping around.
+    fn jump_target(&self, state: &SelectState, c: char) -> Option<usize> {
+        let prefix = c.to_lowercase().to_string();
+        let match_count = state.matches.len();
+        (1..=match_count)
+            .map(|offset| (state.cursor + offset) % match_count)
+            .find(|&pos| {
+                let i = state.matches[pos];
+                !self.is_disabled(i) && self.display_text(i).decolored().trim_start().to_lowercase().starts_with(&prefix)
+            })
+    }
+
+    /// Returns the digit (from 1 to 9) typed by `key` if the selection is numbered.
+    fn number_key(&self, key: &Key) -> Option<usize> {
+        match key {
+            Key::Char(c @ '1'..='9') if self.is_numbered => c.to_digit(10).map(|digit| digit as usize),
+            _ => None,
+        }
+    }
+
+    /// Returns the rendered option lines (joined by newlines) as they would appear with the option at `selected_index` under the cursor, without touching the terminal.
+    #[must_use]
+    pub fn render_frame(&self, selected_index: usize) -> String {
+        self.render_lines(&self.state_at(selected_index), None).join("\n")
+    }
+
+    /// Returns the rendered option lines like [`render_frame()`](Select::render_frame), but truncated as they would be on a terminal that is `columns` wide (see [`no_truncate()`](Select::no_truncate)).
+    #[must_use]
+    pub fn render_frame_truncated(&self, selected_index: usize, columns: usize) -> String {
+        let columns = Some(columns).filter(|_| self.truncates);
+        self.render_lines(&self.state_at(selected_index), columns).join("\n")
+    }
+
+    /// Returns the state of the selection before any keys are pressed, but with the option at `selected_index` under the cursor.
+    fn state_at(&self, selected_index: usize) -> SelectState {
+        let mut state = self.initial_state();
+        state.cursor = selected_index;
+        state.view_offset = scrolled_offset(selected_index, 0, self.window_len(state.matches.len()));
+        state
+    }
+
+    /// Runs the selection against `key_sequence` instead of reading keys from the terminal, returning the value of the confirmed option.
+    /// Nothing is rendered, which makes this useful for testing code that builds a `Select`; the [`on_change()`](Select::on_change) callback is still called whenever the option under the cursor changes,
+    /// and the function of the [`with_add_option()`](Select::with_add_option) entry is still called when it is chosen.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if the key sequence ends before an option is confirmed,
+    /// or [`PromptError::Cancelled`] if it reaches [`Key::CtrlC`] first.
+    /// Returns [`PromptError::InvalidDefault`] if the default option index (see [`default_opt()`](Select::default_opt)) is out of bounds or the default option is disabled.
+    pub fn simulate(self, key_sequence: Vec<Key>) -> PromptResult<C> {
+        self.validate()?;
+        let mut state = self.initial_state();
+        let mut previewed_index = None;
+        for key in key_sequence {
+            let selected_index = state.matches.get(state.cursor).copied();
+            if let (Some(change_fn), Some(i)) = (&self.on_change, selected_index) {
+                if let Some(select_opt) = self.options.borrow().get(i).filter(|_| selected_index != previewed_index) {
+                    change_fn(i, &select_opt.value);
+                }
+            }
+            previewed_index = selected_index;
+
+            if key == Key::CtrlC {
+                return Err(PromptError::Cancelled);
+            }
+            match self.handle_key(&mut state, &key, false) {
+                KeyOutcome::Confirmed(_, value) => return Ok(value),
+                KeyOutcome::Add => state = self.state_at(self.push_added_option()?),
+                KeyOutcome::Continue | KeyOutcome::Cancelled => {}
+            }
+        }
+        Err(io::Error::new(io::ErrorKind::UnexpectedEof, "the key sequence ended before an option was confirmed").into())
+    }
+
+    /// Runs the selection loop, returning the index and value of the confirmed option, or `None` if the selection is `cancellable` and the cancel key is pressed.
+    fn run(&self, msg: D, cancellable: bool) -> PromptResult<Option<(usize, C)>> {
+        self.validate()?;
+        if !self.target.is_interactive() {
+            loop {
+                let answer = crate::read_line()?; // an empty line stands in for the cancel key
+                if answer.is_empty() && cancellable {
+                    return Ok(None);
+                }
+                if self.is_add_answer(&answer) {
+                    self.push_added_option()?;
+                    continue;
+                }
+
+                let options = self.options.borrow();
+                return find_option(&options, &answer)
+                    .map(|i| Some((i, options[i].value.clone())))
+                    .ok_or_else(|| crate::unrecognized_answer(&answer));
+            }
+        }
+
+        let term = self.target.term();
+        let mut state = self.initial_state();
+        let mut animates_entry = self.has_entry_animation && !env::var("TERM").is_ok_and(|term| term == "dumb");
+        let mut drawn_lines = 0;
+        let mut previewed_index = None;
+        let mut drawn_preview_lines = 0;
+
+        for _ in 0..self.padding {
+            term.write_line("")?;
+        }
+
+        if let Some(step_header) = self.step_header() {
+            term.write_line(&step_header)?;
+        }
+        term.write_line(&msg.to_string())?;
+
+        loop {
+            let lines = self.render_lines_on(&term, &state);
+            let selected_index = state.matches.get(state.cursor).copied();
+            if selected_index != previewed_index || lines.len() != drawn_lines {
+                // redraw over the lines (and the preview) drawn last iteration
+                term.clear_last_lines(drawn_lines + drawn_preview_lines)?;
+                for line in &lines {
+                    term.write_line(line)?;
+                }
+                for line in self.preview_content(selected_index) {
+                    term.write_line(&line)?;
+                }
+                self.preview(&term, selected_index)?;
+                previewed_index = selected_index;
+                drawn_preview_lines = self.preview_lines;
+            } else {
+                self.redraw_options(&term, &lines, drawn_lines)?;
+            }
+            drawn_lines = lines.len();
+
+            term.hide_cursor()?;
+
+            if animates_entry {
+                animates_entry = false;
+                state.is_flashing = true;
+                self.redraw_options(&term, &self.render_lines_on(&term, &state), drawn_lines)?;
+                thread::sleep(ENTRY_ANIMATION_DURATION);
+                state.is_flashing = false;
+                self.redraw_options(&term, &lines, drawn_lines)?;
+            }
+
+            let answer = match self.handle_key(&mut state, &crate::read_key(&term)?, cancellable) {
+                KeyOutcome::Continue => continue,
+                KeyOutcome::Confirmed(index, value) => Some((index, value)),
+                KeyOutcome::Cancelled => None,
+                KeyOutcome::Add => {
+                    // clear the options so that `add_fn` can prompt below the message, then redraw them from scratch
+                    term.clear_last_lines(drawn_lines + drawn_preview_lines)?;
+                    term.show_cursor()?;
+                    state = self.state_at(self.push_added_option()?);
+                    drawn_lines = 0;
+                    drawn_preview_lines = 0;
+                    previewed_index = None;
+                    continue;
+                }
+            };
+
+            if self.clear_after_response || answer.is_none() {
+                let header_lines = usize::from(self.step.is_some());
+                term.clear_last_lines(drawn_lines + self.preview_lines + self.padding + header_lines + 1)?; // + 1 implies we also want to clear the prompt line
+            }
+            term.show_cursor()?;
+            return Ok(answer);
+        }
+    }
+
+    /// Returns the lines to render on `term` for the current state of the selection, truncated (with an ellipsis) to the width of the terminal unless [`no_truncate()`](Select::no_truncate) is set.
+    /// The prefix, alignment padding and scrollbar are all counted towards the width of a line.
+    fn render_lines_on(&self, term: &Term, state: &SelectState) -> Vec<String> {
+        let columns = self.truncates.then(|| usize::from(term.size().1));
+        self.render_lines(state, columns)
+    }
+
+    /// Redraws `lines` over the `drawn_lines` option lines drawn last, leaving the preview below them untouched.
+    fn redraw_options(&self, term: &Term, lines: &[String], drawn_lines: usize) -> io::Result<()> {
+        term.move_cursor_up(self.preview_lines)?;
+        term.clear_last_lines(drawn_lines)?;
+        for line in lines {
+            term.write_line(line)?;
+        }
+        term.move_cursor_down(self.preview_lines)
+    }
+
+    /// Returns the lines of the preview area for the option at `selected_index` (if any): the lines returned by `preview_fn` (if set), truncated or padded with blank lines to the preview height.
+    fn preview_content(&self, selected_index: Option<usize>) -> Vec<String> {
+        let options = self.options.borrow();
+        let mut lines = match (&self.preview_fn, selected_index.and_then(|i| options.get(i))) {
+            (Some(preview_fn), Some(select_opt)) => preview_fn(&select_opt.value),
+            _ => Vec::new(),
+        };
+        lines.resize(self.preview_lines, String::new());
+        lines
+    }
+
+    /// Calls the `on_change` callback (if set) for the option at `selected_index` (if any), with the cursor moved to the start of the preview lines.
+    /// The cursor is restored to the line below the preview lines afterwards.
+    fn preview(&self, term: &Term, selected_index: Option<usize>) -> io::Result<()> {
+        let (Some(change_fn), Some(i)) = (&self.on_change, selected_index) else {
+            return Ok(());
+        };
+
+        term.write_str(SAVE_CURSOR)?;
+        term.move_cursor_up(self.preview_lines)?;
+        if let Some(select_opt) = self.options.borrow().get(i) {
+            change_fn(i, &select_opt.value);
+        }
+        term.flush()?;
+        term.write_str(RESTORE_CURSOR)
+    }
+}
+
+/// Prompts the user to choose one of `options` with a [`Select`] with the default settings, returning the value of the chosen option.
+///
+/// This is the quick-start shortcut for a `Select`, like [`prompt()`](crate::prompt) is for a [`Prompter`](crate::prompting::Prompter).
+///
+/// # Errors
+/// Propogates the same errors as [`Select::prompt()`].
+pub fn select<C: Clone, D: Display>(prompt: D, prefix: D, options: Vec<SelectOpt<C, D>>) -> PromptResult<C> {
+    Select::new(prefix, options).prompt(prompt)
+}
+
+/// Prompts the user to toggle any number of `options` with a [`MultiSelect`] with the default settings, returning the values of the toggled options.
+///
+/// This is the quick-start shortcut for a `MultiSelect`, like [`select()`] is for a [`Select`].
+///
+/// # Errors
+/// Propogates the same errors as [`MultiSelect::prompt()`].
+pub fn select_multiple<C: Clone, D: Display>(prompt: D, prefix: D, options: Vec<SelectOpt<C, D>>) -> PromptResult<Vec<C>> {
+    MultiSelect::new(prefix, options).prompt(prompt)
+}
+
+/// How typed queries filter the options of a searchable [`Select`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SearchMode {
+    /// Options match when their display text contains the query as a case-insensitive subsequence.
+    Fuzzy,
+    /// Options match when their display text contains the query as a case-insensitive substring.
+    Substring,
+}
+
+/// The result of handling a keystroke in a [`Select`].
+enum KeyOutcome<C> {
+    /// The selection is still in progress.
+    Continue,
+    /// The option at the contained index (into the selection's options), with the contained value, was confirmed.
+    Confirmed(usize, C),
+    /// The selection was cancelled.
+    Cancelled,
+    /// The "add" entry was chosen.
+    Add,
+}
+
+/// The "add" entry of a [`Select`], which adds an option when chosen (see [`Select::with_add_option`]).
+struct AddEntry<C, D> {
+    /// The text rendered for the entry, below the options.
+    label: D,
+    /// Returns the value of the option to add.
+    add_fn: AddFn<C>,
+    /// Converts the value of the added option into its display text.
+    display_fn: fn(&C) -> D,
+}
+
+/// The interactive state of a [`Select`] while it is being prompted.
+struct SelectState {
+    /// The search query typed so far (always empty unless the selection is searchable).
+    query: String,
+    /// The indices (into the selection's options) of the options that match the query, in display order.
+    matches: Vec<usize>,
+    /// The position (into `matches`) of the option under the cursor.
+    cursor: usize,
+    /// The position (into `matches`) of the first rendered option.
+    view_offset: usize,
+    /// Determines whether the option under the cursor is rendered in reversed colors, as during the entry animation.
+    is_flashing: bool,
+    /// Determines whether the options are sorted by their display text, rather than in insertion order.
+    is_sorted: bool,
+}
+
+/// Returns the view offset of a window of `window_len` rows, scrolled as little as possible from `view_offset` so that `selected_index` is visible.
+fn scrolled_offset(selected_index: usize, view_offset: usize, window_len: usize) -> usize {
+    if selected_index < view_offset {
+        selected_index
+    } else if selected_index >= view_offset + window_len {
+        selected_index + 1 - window_len
+    } else {
+        view_offset
+    }
+}
+
+/// Appends a scrollbar to the option lines (at `option_lines` in `lines`), padding them so that the scrollbar is rendered in a single column.
+/// The thumb's position is proportional to the `cursor` position among all `match_count` options.
+fn add_scrollbar(lines: &mut [String], option_lines: &[usize], cursor: usize, match_count: usize) {
+    let rows = option_lines.len();
+    let thumb_len = (rows * rows / match_count).max(1);
+    let thumb_start = (cursor * rows / match_count).min(rows - thumb_len);
+
+    let width = |line: &String| line.decolored().graphemes(true).count();
+    let max_width = option_lines.iter().map(|&i| width(&lines[i])).max().unwrap_or(0);
+    for (row, &i) in option_lines.iter().enumerate() {
+        let glyph = if (thumb_start..thumb_start + thumb_len).contains(&row) { SCROLLBAR_THUMB } else { SCROLLBAR_TRACK };
+        let padding = " ".repeat(max_width - width(&lines[i]) + 1);
+        lines[i] = format!("{}{padding}{glyph}", lines[i]);
+    }
+}
+
+/// Returns the index of the enabled option identified by `answer`, matching (case-insensitively) its decolored display text or else its (zero-based) index.
+fn find_option<C, D>(options: &[SelectOpt<C, D>], answer: &str) -> Option<usize>
+where
+    C: Clone,
+    D: Display,
+{
+    let answer = answer.to_lowercase();
+    let i = options
+        .iter()
+        .position(|opt| opt.display_text.to_string().decolored().trim().to_lowercase() == answer)
+        .or_else(|| answer.parse::<usize>().ok().filter(|&i| i < options.len()))?;
+    (!options[i].disabled).then_some(i)
+}
+
+/// Returns the character positions in `text` that match each character of `query` in order (case-insensitively), or `None` if `query` is not a subsequence of `text`.
+fn fuzzy_match(text: &str, query: &str) -> Option<Vec<usize>> {
+    let mut positions = Vec::new();
+    let mut text_chars = text.chars().enumerate();
+
+    for q in query.chars() {
+        let (pos, _) = text_chars.find(|(_, c)| c.to_lowercase().eq(q.to_lowercase()))?;
+        positions.push(pos);
+    }
+    Some(positions)
+}
+
+/// Returns the character positions in `text` of the first occurrence of `query` (case-insensitively), or `None` if `query` is not a substring of `text`.
+fn substring_match(text: &str, query: &str) -> Option<Vec<usize>> {
+    let text: Vec<char> = text.chars().collect();
+    let query: Vec<char> = query.chars().collect();
+    if query.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let start = text
+        .windows(query.len())
+        .position(|window| window.iter().zip(&query).all(|(c, q)| c.to_lowercase().eq(q.to_lowercase())))?;
+    Some((start..start + query.len()).collect())
+}
+
+/// Returns `text` with the characters at `positions` emboldened.
+fn highlighted(text: &str, positions: &[usize]) -> String {
+    text.chars()
+        .enumerate()
+        .map(|(pos, c)| if positions.contains(&pos) { style(c).bold().to_string() } else { c.to_string() })
+        .collect()
+}
+
+/// The character repeated to form the separator rendered above a group header in a grouped [`Select`].
+const GROUP_SEPARATOR: &str = "─";
+/// The glyph rendered for the track of the scrollbar in a [`Select`] with hidden options.
+const SCROLLBAR_TRACK: &str = "│";
+/// The glyph rendered for the thumb of the scrollbar in a [`Select`] with hidden options.
+const SCROLLBAR_THUMB: &str = "█";
+/// The ellipsis that ends a line of a [`Select`] truncated to the width of the terminal.
+const TRUNCATION_TAIL: &str = "…";
+/// The width of the scrollbar of a [`Select`], including the space that separates it from the longest option.
+const SCROLLBAR_WIDTH: usize = 2;
+
+/// How long the option under the cursor is highlighted by the entry animation of a [`Select`] (see [`Select::with_entry_animation`]).
+const ENTRY_ANIMATION_DURATION: Duration = Duration::from_millis(100);
+
+/// The separator rendered between options in an inline [`Select`].
+const INLINE_SEPARATOR: &str = "  ";
+/// The label rendered ahead of the query in a searchable [`Select`].
+const SEARCH_LABEL: &str = "Search: ";
+/// The glyph rendered after the query in a searchable [`Select`], in place of the (hidden) cursor.
+const SEARCH_CURSOR: &str = "▌";
+/// The default message rendered when no options match the query in a searchable [`Select`].
+const NO_RESULTS_MSG: &str = "No results.";
+/// The escape code that saves the cursor position, restored by [`RESTORE_CURSOR`] after a [`Select`] calls its `on_change` callback.
+const SAVE_CURSOR: &str = "\x1b7";
+/// The escape code that restores the cursor position saved by [`SAVE_CURSOR`].
+const RESTORE_CURSOR: &str = "\x1b8";
+
+/// The glyph rendered ahead of an option that has been toggled on in a [`MultiSelect`].
+const CHECKED_BOX: &str = "[x]";
+/// The glyph rendered ahead of an option that has not been toggled on in a [`MultiSelect`].
+const UNCHECKED_BOX: &str = "[ ]";
+
+/// Represents a multi-select (checkbox) dialog.
+#[must_use]
+pub struct MultiSelect<C, D>
 where
-    C: Copy,
+    C: Clone,
     D: Display,
 {
-    /// The index of the default option (e.g. 0 represents the first option in the `options` vector).
+    /// The index of the option the cursor initially rests on (e.g. 0 represents the first option in the `options` vector).
     default_index: usize,
     /// The number of lines that separates the prompt from other text.
     padding: usize,
-    /// Determines if the selected and unselected answers should be aligned.
+    /// Determines if the highlighted and non-highlighted answers should be aligned.
     is_aligned: bool,
-    /// The prefix to print ahead of the currently selected item.
+    /// The prefix to print ahead of the item the cursor is on.
     prefix: D,
     /// Determines whether to clear the prompt after an answer is given.
     clear_after_response: bool,
+    /// The minimum number of options that must be toggled on before the selection can be confirmed.
+    min_selections: usize,
+    /// The maximum number of options that may be toggled on for the selection to be confirmed, if any.
+    max_selections: Option<usize>,
     /// The options that the selection displays when prompting.
     options: Vec<SelectOpt<C, D>>,
 }
 
-/// Represents a single option in a [`Select`](Select).
-pub struct SelectOpt<C, D>
-where
-    C: Copy,
-    D: Display,
-{
-    /// The text to render by default.
-    pub display_text: D,
-    /// The text to render when the option is currently selected.
-    pub selected_text: Option<D>,
-    /// The value that the option represents. This will be returned by the prompter.
-    pub value: C,
-}
-
-impl<C, D> SelectOpt<C, D>
-where
-    C: Copy,
-    D: Display
-{
-    /// Constructs a new option.
-    /// Prompts will return the `value` passed into this struct.
-    pub fn new(display_text: D, selected_text: Option<D>, value: C) -> Self {
-        Self {
-            display_text,
-            selected_text,
-            value,
-        }
-    }
-}
-
-impl<C, D> Select<C, D>
+impl<C, D> MultiSelect<C, D>
 where
-    C: Copy,
+    C: Clone,
     D: Display,
 {
-    /// Creates a new selection with a collection of tuples containing the following items:
-    /// - the text to print
-    /// - the thing that maps to that text (i.e. if that text is selected, the corresponding thing is returned by the [`prompt()`](Select::prompt) function).
+    /// Creates a new multi-selection from a collection of [`SelectOpt`]s.
+    /// An option's `selected_text` (if any) is rendered while that option is toggled on.
     pub fn new(selected_prefix: D, options: Vec<SelectOpt<C, D>>) -> Self {
-
         Self {
             default_index: 0,
             padding: 0,
             is_aligned: false,
             prefix: selected_prefix,
             clear_after_response: false,
+            min_selections: 0,
+            max_selections: None,
             options,
         }
     }
@@ -86,7 +1454,7 @@ where
     }
 
     /// Sets the padding, or the number of lines that separates the selection from the text above it.
-    /// Consumes the `Select` and returns a transformed one.
+    /// Consumes the `MultiSelect` and returns a transformed one.
     pub fn padding(self, num_lines: usize) -> Self {
         Self {
             padding: num_lines,
@@ -94,8 +1462,8 @@ where
         }
     }
 
-    /// Sets the prefix for the selected item.
-    /// Consumes the `Select` and returns a transformed one.
+    /// Sets the prefix for the item the cursor is on.
+    /// Consumes the `MultiSelect` and returns a transformed one.
     pub fn prefix(self, selected_prefix: D) -> Self {
         Self {
             prefix: selected_prefix,
@@ -103,8 +1471,8 @@ where
         }
     }
 
-    /// Makes the options aligned together, instead of having to manually indent them in the selection's options. Note that added spaces in the default text may cause unexpected spacing.
-    /// Consumes the `Select` and returns a transformed one.
+    /// Makes the options aligned together, instead of having to manually indent them in the selection's options.
+    /// Consumes the `MultiSelect` and returns a transformed one.
     pub fn aligned(self) -> Self {
         Self {
             is_aligned: true,
@@ -113,7 +1481,7 @@ where
     }
 
     /// Sets whether the prompt should be cleared after a response is given.
-    /// Consumes the `Select` and returns a transformed one.
+    /// Consumes the `MultiSelect` and returns a transformed one.
     pub fn clear_after(self) -> Self {
         Self {
             clear_after_response: true,
@@ -121,8 +1489,8 @@ where
         }
     }
 
-    /// Sets the default option (the thing that's initially selected).
-    /// Consumes the `Select` and returns a transformed one.
+    /// Sets the option the cursor initially rests on.
+    /// Consumes the `MultiSelect` and returns a transformed one.
     pub fn default_opt(self, default_index: usize) -> Self {
         Self {
             default_index,
@@ -130,8 +1498,75 @@ where
         }
     }
 
+    /// Sets the minimum number of options that must be toggled on before the selection can be confirmed.
+    /// Consumes the `MultiSelect` and returns a transformed one.
+    pub fn min_selections(self, n: usize) -> Self {
+        Self {
+            min_selections: n,
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of options that may be toggled on for the selection to be confirmed.
+    /// Once the maximum is reached, attempting to toggle on another option prints an error line below the options instead.
+    /// Consumes the `MultiSelect` and returns a transformed one.
+    pub fn max_selections(self, n: usize) -> Self {
+        Self {
+            max_selections: Some(n),
+            ..self
+        }
+    }
+
+    /// Checks the invariants configured through the builder methods, returning [`PromptError::InvalidDefault`] describing the first one that does not hold.
+    fn validate(&self) -> PromptResult<()> {
+        let option_count = self.options.len();
+        if self.default_index >= option_count {
+            let msg = format!("the default option index ({}) is out of bounds for a selection with {option_count} options", self.default_index);
+            return Err(PromptError::InvalidDefault(msg));
+        }
+        Ok(())
+    }
+
+    /// Reads the toggled options from a line of stdin, as a comma-separated list of display texts or (zero-based) indices (see [`is_interactive()`](crate::is_interactive)).
+    fn read_answer(&self) -> PromptResult<Vec<C>> {
+        let answer = crate::read_line()?;
+        let mut is_toggled = vec![false; self.options.len()];
+        for token in answer.split(',').map(str::trim).filter(|token| !token.is_empty()) {
+            let i = find_option(&self.options, token).ok_or_else(|| crate::unrecognized_answer(token))?;
+            is_toggled[i] = true;
+        }
+
+        if !self.is_count_allowed(is_toggled.iter().filter(|&&toggled| toggled).count()) {
+            return Err(PromptError::ValidationFailed(self.count_err_msg()));
+        }
+        Ok(self
+            .options
+            .iter()
+            .zip(is_toggled)
+            .filter(|(_, toggled)| *toggled)
+            .map(|(select_opt, _)| select_opt.value.clone())
+            .collect())
+    }
+
+    /// Returns whether `count` toggled options satisfies the configured selection bounds.
+    fn is_count_allowed(&self, count: usize) -> bool {
+        count >= self.min_selections && self.max_selections.is_none_or(|max| count <= max)
+    }
+
+    /// Returns the message printed when the user attempts to confirm with a disallowed number of toggled options.
+    fn count_err_msg(&self) -> String {
+        match self.max_selections {
+            Some(max) if self.min_selections > 0 => format!("Please select between {} and {max} options.", self.min_selections),
+            Some(max) => format!("Please select at most {max} options."),
+            None => format!("Please select at least {} options.", self.min_selections),
+        }
+    }
+
     /// Prompts the user for an input by printing `msg` with `println!()`.
-    /// This function will print the textual part of all options, and return the corresponding value represented by it (i.e. a `value` -- which conforms to type `C`).
+    /// The arrow keys move the cursor, the spacebar toggles the option under the cursor, and enter confirms the selection.
+    /// Returns the values of every toggled option, in the order that the options are listed.
+    ///
+    /// If the number of toggled options is outside of the configured bounds, an error line is printed below the options and the selection is not confirmed.
     ///
     /// # Errors
     /// Propogates the following errors:
@@ -139,80 +1574,105 @@ where
     /// - [`Term::hide_cursor`]
     /// - [`Term::show_cursor`]
     /// - [`Term::clear_last_lines`]
-    pub fn prompt(&self, msg: D) -> io::Result<C> {
+    ///
+    /// Returns [`PromptError::Cancelled`] if Ctrl+C is pressed,
+    /// or [`PromptError::InvalidDefault`] before anything is rendered if there are no options or the default option index (see [`default_opt()`](MultiSelect::default_opt)) is out of bounds.
+    pub fn prompt(&self, msg: D) -> PromptResult<Vec<C>> {
+        self.validate()?;
+        if !crate::is_interactive() {
+            return self.read_answer();
+        }
+
         let term = Term::stdout();
-        let mut selected_index = self.default_index;
+        let mut cursor_index = self.default_index;
+        let mut is_toggled = vec![false; self.options.len()];
+        let mut count_err: Option<String> = None;
         let prefix_char_count = self.prefix.to_string().decolored().graphemes(true).count();
 
         for _ in 0..self.padding {
             println!();
         }
 
-        println!("{}", msg);
+        println!("{msg}");
 
         // print lines to redraw over
         for _ in 0..self.options.len() {
             println!();
         }
+        let mut drawn_lines = self.options.len();
 
         loop {
-            // redraw over last x lines
-            term.clear_last_lines(self.options.len())?;
+            // redraw over the lines drawn last iteration (including any error line)
+            term.clear_last_lines(drawn_lines)?;
 
             // print the items
-            for (i, SelectOpt { display_text, selected_text, .. }) in self.options.iter().enumerate()
+            for (i, select_opt) in self.options.iter().enumerate()
             {
-                let s = match (i == selected_index, selected_text)
-                {
-                    (true, None) => format!("{}{}", self.prefix, display_text),
-                    (true, Some(sel_str)) => format!("{}{}", self.prefix, sel_str),
-                    _ => {
-                        if self.is_aligned {
-                            format!("{}{}", " ".repeat(prefix_char_count), display_text)
-                        } else {
-                            display_text.to_string() // TODO consider if dereferencing &str and calling str::to_string is faster than &str::to_string
-                        }
-                    }
+                let SelectOpt { display_text, selected_text, disabled, .. } = select_opt;
+                let checkbox = if is_toggled[i] { CHECKED_BOX } else { UNCHECKED_BOX };
+                let text = match (is_toggled[i], selected_text) {
+                    _ if *disabled => select_opt.disabled_text(),
+                    (true, Some(sel_str)) => sel_str.to_string(),
+                    _ => display_text.to_string(),
+                };
+
+                let s = if i == cursor_index {
+                    format!("{}{checkbox} {text}", self.prefix)
+                } else if self.is_aligned {
+                    format!("{}{checkbox} {text}", " ".repeat(prefix_char_count))
+                } else {
+                    format!("{checkbox} {text}")
                 };
 
-                println!("{}", s);
-                // println!("{}", if i == selected_index && selected_option.is_some() {format!("{}{}", self.selected_prefix, selected_option.unwrap()) } else {s});
+                println!("{s}");
+            }
+            drawn_lines = self.options.len();
+
+            if let Some(err) = count_err.take() {
+                println!("{err}");
+                drawn_lines += 1;
             }
 
             term.hide_cursor()?;
 
-            // TODO consider integer wrapping
-            match term.read_key()? {
+            match crate::read_key(&term)? {
                 Key::ArrowUp => {
-                    if selected_index as isize == -1 {
-                        selected_index = self.options.len() - 1;
-                    } else {
-                        selected_index = ((selected_index as i32 - 1 + self.options.len() as i32)
-                            % self.options.len() as i32)
-                            as usize;
-                    }
+                    cursor_index = if cursor_index == 0 { self.options.len() - 1 } else { cursor_index - 1 };
                 }
 
                 Key::ArrowDown => {
-                    if selected_index as isize == -1 {
-                        selected_index = 0;
-                    } else {
-                        selected_index =
-                            ((selected_index as u64 + 1) % self.options.len() as u64) as usize;
+                    cursor_index = (cursor_index + 1) % self.options.len();
+                }
+
+                Key::Char(' ') if !self.options.get(cursor_index).is_some_and(|opt| opt.disabled) => {
+                    let toggled_count = is_toggled.iter().filter(|&&toggled| toggled).count();
+                    if let Some(toggled) = is_toggled.get_mut(cursor_index) {
+                        if !*toggled && self.max_selections.is_some_and(|max| toggled_count >= max) {
+                            count_err = Some(self.count_err_msg()); // toggling on another option would exceed the maximum
+                            continue;
+                        }
+                        *toggled = !*toggled;
                     }
                 }
 
                 Key::Enter => {
-                    let select_opt = self
-                        .options
-                        .get(selected_index)
-                        .expect("unexpectedly failed to get selected item");
+                    let toggled_count = is_toggled.iter().filter(|&&toggled| toggled).count();
+                    if !self.is_count_allowed(toggled_count) {
+                        count_err = Some(self.count_err_msg());
+                        continue;
+                    }
 
                     if self.clear_after_response {
-                        term.clear_last_lines(self.options.len() + self.padding + 1)?; // + 1 implies we also want to clear the prompt line
+                        term.clear_last_lines(drawn_lines + self.padding + 1)?; // + 1 implies we also want to clear the prompt line
                     }
                     term.show_cursor()?;
-                    return Ok(select_opt.value);
+                    return Ok(self
+                        .options
+                        .iter()
+                        .zip(is_toggled)
+                        .filter(|(_, toggled)| *toggled)
+                        .map(|(select_opt, _)| select_opt.value.clone())
+                        .collect());
                 }
                 _ => {}
             }
@@ -220,26 +1680,72 @@ where
     }
 }
 
-trait Decolor {
-    /// Removes color escape sequences from a string.
-    fn decolored(&self) -> Self;
+/// Strips ANSI escape sequences (such as colors and hyperlinks) from strings, such as to measure the width of styled text.
+pub trait Decolor {
+    /// The decolored form of the string.
+    type Output<'a> where Self: 'a;
+
+    /// Removes ANSI escape sequences from a string.
+    fn decolored(&self) -> Self::Output<'_>;
 }
 
-impl Decolor for String {
-    fn decolored(&self) -> Self {
-        let mut s = Self::new();
+impl Decolor for str {
+    type Output<'a> = Cow<'a, Self>;
+
+    /// Removes ANSI escape sequences from a string, only allocating a new string if there are any to remove.
+    ///
+    /// This covers CSI sequences (such as colors and cursor movements, e.g. `\x1B[31m` and `\x1B[2A`), OSC sequences terminated by BEL or ST (such as hyperlinks),
+    /// and the other two-character (`Fe`) sequences. An unterminated sequence is kept as-is.
+    fn decolored(&self) -> Cow<'_, Self> {
+        if !self.contains('\x1B') {
+            return Cow::Borrowed(self);
+        }
 
-        let mut tail_str = &self[..];
-        while let Some(split_index) = tail_str.find(|c: char| c.is_ascii_control() && c == '\x1B')
+        let mut s = String::with_capacity(self.len());
+        let mut tail_str = self;
+        while let Some(split_index) = tail_str.find('\x1B')
         {
             let (start_str, split_str) = tail_str.split_at(split_index);
+            s.push_str(start_str);
 
-            if let Some(split_end_inc_index) = split_str.find('m') {
-                s.push_str(start_str);
-                tail_str = &split_str[split_end_inc_index+1..]; // excludes 'm' from the remaining string ref to parse
-            }
+            let Some(sequence_len) = escape_sequence_len(split_str) else {
+                tail_str = split_str; // an unterminated escape sequence is kept as-is
+                break;
+            };
+            tail_str = &split_str[sequence_len..];
+        }
+        s.push_str(tail_str); // if there aren't any other escape sequences, just concat the rest of the string since there's nothing to remove
+        Cow::Owned(s)
+    }
+}
+
+/// Returns the length (in bytes) of the escape sequence at the start of `s` (which starts with `\x1B`), or `None` if it is unterminated or not recognized.
+fn escape_sequence_len(s: &str) -> Option<usize> {
+    let body = &s[1..];
+    match body.chars().next()? {
+        '[' => {
+            // CSI: parameter and intermediate bytes, then a final byte in `@` to `~`
+            let final_index = body[1..].find(|c: char| ('@'..='~').contains(&c))?;
+            Some(1 + 1 + final_index + 1)
+        }
+        ']' | 'P' | 'X' | '^' | '_' => {
+            // OSC (or another control string): terminated by BEL or ST (`\x1B\\`)
+            let (end_index, terminator_len) = body.char_indices().skip(1).find_map(|(i, c)| match c {
+                '\x07' => Some((i, 1)),
+                '\x1B' if body[i + 1..].starts_with('\\') => Some((i, 2)),
+                _ => None,
+            })?;
+            Some(1 + end_index + terminator_len)
         }
-        s.push_str(tail_str); // if there aren't any other color codes, just concat the rest of the string since there's nothing to remove
-        s
+        '@'..='_' => Some(2), // Fe
+        _ => None,
+    }
+}
+
+impl Decolor for String {
+    type Output<'a> = Self;
+
+    fn decolored(&self) -> Self {
+        self.as_str().decolored().into_owned()
     }
 }
\ No newline at end of file
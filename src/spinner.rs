@@ -0,0 +1,81 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use console::Term;
+
+/// The glyphs cycled through by a [`Spinner`], in order.
+const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+/// The time between each frame of a [`Spinner`].
+const FRAME_INTERVAL: Duration = Duration::from_millis(80);
+
+/// An animated spinner rendered on the current line, used to show that a long-running task (such as a slow predicate) is in progress.
+///
+/// The spinner is animated by a background thread from the moment it is created until it is finished or dropped,
+/// at which point the line is cleared and the cursor is shown again (even if the task panicked).
+#[must_use = "the spinner stops as soon as it is dropped"]
+pub struct Spinner {
+    /// Whether the background thread should keep animating.
+    is_running: Arc<AtomicBool>,
+    /// The background thread animating the spinner, until it is stopped.
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Spinner {
+    /// Starts animating a spinner, followed by `message`, on the current line.
+    ///
+    /// Terminal errors in the background thread are ignored, as there is no caller to propogate them to.
+    /// Nothing is rendered when not [interactive](crate::is_interactive).
+    pub fn new(message: &str) -> Self {
+        let is_running = Arc::new(AtomicBool::new(true));
+        if !crate::is_interactive() {
+            return Self {
+                is_running,
+                handle: None,
+            };
+        }
+
+        let message = message.to_string();
+
+        let handle = thread::spawn({
+            let is_running = Arc::clone(&is_running);
+            move || {
+                let term = Term::stdout();
+                let _ = term.hide_cursor();
+                for frame in FRAMES.iter().cycle() {
+                    if !is_running.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    let _ = term.clear_line();
+                    let _ = term.write_str(&format!("{frame} {message}"));
+                    thread::sleep(FRAME_INTERVAL);
+                }
+            }
+        });
+
+        Self {
+            is_running,
+            handle: Some(handle),
+        }
+    }
+
+    /// Stops the spinner and clears its line.
+    pub fn finish(self) {
+        drop(self); // the work is done when the spinner is dropped
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.is_running.store(false, Ordering::Relaxed);
+        let Some(handle) = self.handle.take() else {
+            return; // nothing was rendered
+        };
+        let _ = handle.join();
+
+        let term = Term::stdout();
+        let _ = term.clear_line();
+        let _ = term.show_cursor();
+    }
+}
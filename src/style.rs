@@ -0,0 +1,11 @@
+/// A bold green arrow, followed by two spaces.
+pub const ARROW_PREFIX: &str = "\x1b[1;32m➤\x1b[0m  ";
+
+/// A bold white bullet, followed by a space.
+pub const BULLET_PREFIX: &str = "\x1b[1;37m•\x1b[0m ";
+
+/// A bold green checkmark, followed by a space.
+pub const CHECK_PREFIX: &str = "\x1b[1;32m✓\x1b[0m ";
+
+/// A bold cyan pointer, followed by a space.
+pub const POINTER_PREFIX: &str = "\x1b[1;36m❯\x1b[0m ";
@@ -0,0 +1,217 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io;
+
+use console::Term;
+
+use crate::TrimMode;
+
+/// The line-based terminal that a prompt reads its answers from and prints its messages to.
+///
+/// Prompts use the real terminal by default; pass another backend (such as a [`MockTerminal`]) to methods like
+/// [`Prompter::prompt_with_backend()`](crate::prompting::Prompter::prompt_with_backend) and [`Select::prompt_with_backend()`](crate::select::Select::prompt_with_backend) to run them without one.
+pub trait TerminalBackend {
+    /// Prints `prompt` (without a trailing newline), then reads and returns a trimmed line of input.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when there is no more input, or any other I/O error that occurs.
+    fn input(&self, prompt: &str) -> io::Result<String>;
+
+    /// Prints `prompt` (without a trailing newline), then reads and returns a line of input, trimmed according to `mode`.
+    ///
+    /// By default, this reads the line like [`input()`](TerminalBackend::input), trimming it entirely.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when there is no more input, or any other I/O error that occurs.
+    fn input_with_trim(&self, prompt: &str, mode: TrimMode) -> io::Result<String> {
+        let _ = mode;
+        self.input(prompt)
+    }
+
+    /// Prints `prompt` (without a trailing newline), then reads and returns a line of input, echoing `mask` in place of each character typed.
+    ///
+    /// By default, this reads the line like [`input()`](TerminalBackend::input).
+    ///
+    /// # Errors
+    /// Returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when there is no more input, or any other I/O error that occurs.
+    fn input_masked(&self, prompt: &str, mask: char) -> io::Result<String> {
+        let _ = mask;
+        self.input(prompt)
+    }
+
+    /// Prints `prompt` (without a trailing newline), then reads and returns a line of input without echoing anything as it is typed.
+    ///
+    /// By default, this reads the line like [`input()`](TerminalBackend::input).
+    ///
+    /// # Errors
+    /// Returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when there is no more input, or any other I/O error that occurs.
+    fn input_hidden(&self, prompt: &str) -> io::Result<String> {
+        self.input(prompt)
+    }
+
+    /// Clears the last line printed (the rejected input), then prompts again with `error` shown after the prompt on the same line
+    /// (see [`Prompter::with_inline_error()`](crate::prompting::Prompter::with_inline_error)), returning the line of input untrimmed.
+    ///
+    /// By default, this prints `error` on its own line instead, then reads the line like [`input()`](TerminalBackend::input).
+    ///
+    /// # Errors
+    /// Returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) when there is no more input, or any other I/O error that occurs.
+    fn input_with_inline_error(&self, prompt: &str, error: &str) -> io::Result<String> {
+        self.print_line(error)?;
+        self.input(prompt)
+    }
+
+    /// Prints `line`, followed by a newline.
+    ///
+    /// # Errors
+    /// Returns any I/O error that occurs while printing.
+    fn print_line(&self, line: &str) -> io::Result<()>;
+
+    /// Clears the last line printed (or read), leaving the cursor at its start so that it can be overwritten.
+    ///
+    /// By default, this does nothing.
+    ///
+    /// # Errors
+    /// Returns any I/O error that occurs while clearing.
+    fn clear_last_line(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Returns whether [`clear_last_line()`](TerminalBackend::clear_last_line) actually clears lines, so that printed lines can be redrawn in place
+    /// (such as a repeated description; see [`Prompter::with_description()`](crate::prompting::Prompter::with_description)).
+    ///
+    /// By default, this returns `false`.
+    fn can_clear_lines(&self) -> bool {
+        false
+    }
+}
+
+/// The real terminal, which behaves like [`input()`](crate::input) and [`println!`](std::println).
+pub(crate) struct StdTerminal;
+
+impl TerminalBackend for StdTerminal {
+    fn input(&self, prompt: &str) -> io::Result<String> {
+        Ok(crate::input(prompt)?)
+    }
+
+    fn input_with_trim(&self, prompt: &str, mode: TrimMode) -> io::Result<String> {
+        Ok(crate::input_with_trim(prompt, mode)?)
+    }
+
+    fn input_masked(&self, prompt: &str, mask: char) -> io::Result<String> {
+        Ok(crate::password_masked(prompt, mask)?)
+    }
+
+    fn input_hidden(&self, prompt: &str) -> io::Result<String> {
+        Ok(crate::password(prompt)?)
+    }
+
+    fn input_with_inline_error(&self, prompt: &str, error: &str) -> io::Result<String> {
+        crate::input_with_inline_error(prompt, error)
+    }
+
+    fn print_line(&self, line: &str) -> io::Result<()> {
+        println!("{line}");
+        Ok(())
+    }
+
+    fn clear_last_line(&self) -> io::Result<()> {
+        if crate::is_interactive() {
+            Term::stdout().clear_last_lines(1)?;
+        }
+        Ok(())
+    }
+
+    fn can_clear_lines(&self) -> bool {
+        crate::is_interactive()
+    }
+}
+
+/// A [`TerminalBackend`] that answers prompts with pre-loaded lines and records everything printed, for testing prompts deterministically.
+///
+/// ```
+/// use prediput::prompting::Prompter;
+/// use prediput::testing::MockTerminal;
+///
+/// let mock = MockTerminal::new(vec!["abc".to_string(), "42".to_string()]);
+/// let age = Prompter::<u32>::new("Please enter a whole number.").prompt_with_backend("Age: ", &mock).unwrap();
+///
+/// assert_eq!(age, 42);
+/// assert_eq!(mock.output(), vec!["Age: abc", "Please enter a whole number.", "Age: 42"]);
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTerminal {
+    /// The lines that have yet to be "typed", in order.
+    inputs: RefCell<VecDeque<String>>,
+    /// The lines printed so far, with each answer echoed after its prompt.
+    output: RefCell<Vec<String>>,
+}
+
+impl MockTerminal {
+    /// Creates a `MockTerminal` that answers each prompt with the next line of `inputs`.
+    #[must_use]
+    pub fn new(inputs: Vec<String>) -> Self {
+        Self {
+            inputs: RefCell::new(inputs.into()),
+            output: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns every line printed so far, where each prompt is followed by the answer it received (as if it were typed).
+    #[must_use]
+    pub fn output(&self) -> Vec<String> {
+        self.output.borrow().clone()
+    }
+
+    /// Removes and returns the next pre-loaded input.
+    fn next_input(&self) -> io::Result<String> {
+        self.inputs
+            .borrow_mut()
+            .pop_front()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "the mock terminal ran out of inputs"))
+    }
+}
+
+impl TerminalBackend for MockTerminal {
+    fn input(&self, prompt: &str) -> io::Result<String> {
+        self.input_with_trim(prompt, TrimMode::Both)
+    }
+
+    fn input_with_trim(&self, prompt: &str, mode: TrimMode) -> io::Result<String> {
+        let answer = self.next_input()?;
+        self.output.borrow_mut().push(format!("{prompt}{answer}"));
+        Ok(mode.apply(&answer).to_string())
+    }
+
+    fn input_masked(&self, prompt: &str, mask: char) -> io::Result<String> {
+        let answer = self.next_input()?;
+        self.output.borrow_mut().push(format!("{prompt}{}", mask.to_string().repeat(answer.chars().count())));
+        Ok(answer)
+    }
+
+    fn input_hidden(&self, prompt: &str) -> io::Result<String> {
+        let answer = self.next_input()?;
+        self.output.borrow_mut().push(prompt.to_string());
+        Ok(answer)
+    }
+
+    fn input_with_inline_error(&self, prompt: &str, error: &str) -> io::Result<String> {
+        self.clear_last_line()?;
+        self.print_line(&format!("{prompt}{}", crate::inline_error_suffix(error)))?;
+        self.input_with_trim(prompt, TrimMode::None)
+    }
+
+    fn print_line(&self, line: &str) -> io::Result<()> {
+        self.output.borrow_mut().push(line.to_string());
+        Ok(())
+    }
+
+    fn clear_last_line(&self) -> io::Result<()> {
+        self.output.borrow_mut().pop();
+        Ok(())
+    }
+
+    fn can_clear_lines(&self) -> bool {
+        true
+    }
+}
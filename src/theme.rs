@@ -0,0 +1,53 @@
+use std::cell::RefCell;
+
+use console::Style;
+
+thread_local! {
+    /// The theme applied to prompts created on this thread (see [`set_default_theme`]).
+    static DEFAULT_THEME: RefCell<Theme> = RefCell::new(Theme::default());
+}
+
+/// Styling shared across prompt types, so that it can be defined once for an entire application.
+///
+/// A theme can be applied to individual prompts with [`Select::with_theme()`](crate::select::Select::with_theme) and [`Prompter::with_theme()`](crate::prompting::Prompter::with_theme),
+/// or set as the default for every prompt created afterwards (on the current thread) with [`set_default_theme()`].
+/// Builder calls made after a theme is applied override the theme's values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// The prefix to print ahead of the currently selected item in a [`Select`](crate::select::Select).
+    /// Only applied by [`Select::with_theme()`](crate::select::Select::with_theme), as [`Select::new()`](crate::select::Select::new) takes its own prefix.
+    pub select_prefix: String,
+    /// Determines if the selected and unselected options in a [`Select`](crate::select::Select) should be aligned.
+    pub select_aligned: bool,
+    /// The key that answers "yes" to a [`confirm()`](crate::confirm) prompt (matched case-insensitively).
+    pub confirm_yes_char: char,
+    /// The key that answers "no" to a [`confirm()`](crate::confirm) prompt (matched case-insensitively).
+    pub confirm_no_char: char,
+    /// The number of lines that separates a [`Select`](crate::select::Select) from the text above it.
+    pub padding: usize,
+    /// The style applied to conversion and validation messages printed when an input is rejected.
+    pub invalid_style: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            select_prefix: "> ".to_string(),
+            select_aligned: false,
+            confirm_yes_char: 'y',
+            confirm_no_char: 'n',
+            padding: 0,
+            invalid_style: Style::new(),
+        }
+    }
+}
+
+/// Sets the theme used by prompts created afterwards on the current thread.
+pub fn set_default_theme(theme: Theme) {
+    DEFAULT_THEME.with(|default| *default.borrow_mut() = theme);
+}
+
+/// Returns a copy of the current thread's default theme.
+pub(crate) fn default_theme() -> Theme {
+    DEFAULT_THEME.with(|default| default.borrow().clone())
+}
@@ -0,0 +1,27 @@
+use url::{ParseError, Url};
+
+use crate::error::PromptResult;
+use crate::prompting::Prompter;
+
+/// Prompts for an absolute URL, such as `https://example.com/path`.
+///
+/// The user is re-prompted with a message identifying the problem (see [`parse_url()`]) until the input parses.
+/// To require a particular scheme, use a [`Prompter`] with [`Predicate::url_scheme()`](crate::prompting::Predicate::url_scheme) instead.
+///
+/// # Errors
+/// Propogates the same errors as [`Prompter::prompt()`].
+pub fn prompt_url(prompt: &str) -> PromptResult<Url> {
+    Prompter::from_conversion_fn("", Box::new(parse_url)).prompt(prompt)
+}
+
+/// Parses an absolute URL.
+///
+/// # Errors
+/// Returns a message noting that the scheme is missing (suggesting `https://`), that the host is invalid, or otherwise that the URL is invalid.
+pub fn parse_url(val: &str) -> Result<Url, String> {
+    Url::parse(val).map_err(|err| match err {
+        ParseError::RelativeUrlWithoutBase => format!("Missing scheme (did you mean `https://{val}`?)."),
+        ParseError::EmptyHost | ParseError::IdnaError | ParseError::InvalidDomainCharacter | ParseError::InvalidIpv4Address | ParseError::InvalidIpv6Address => "Invalid host.".to_string(),
+        _ => format!("Invalid URL ({err})."),
+    })
+}
@@ -0,0 +1,135 @@
+use std::any::Any;
+use std::fmt::Display;
+
+use crate::error::{PromptError, PromptResult};
+use crate::prompting::Prompter;
+use crate::select::Select;
+
+/// Boxed function that renders the header printed before each step of a [`Wizard`], given the (one-based) step number and the total number of steps.
+pub type StepHeaderFn<'a> = Box<dyn Fn(usize, usize) + 'a>;
+
+/// A single step of a [`Wizard`], which prompts for a value.
+pub trait WizardStep {
+    /// Runs the step, returning the value it prompted for.
+    ///
+    /// # Errors
+    /// Returns any error that occurs while prompting; [`PromptError::Cancelled`] stops the wizard.
+    fn run(&self) -> PromptResult<Box<dyn Any>>;
+}
+
+/// A [`WizardStep`] that prompts for a value of type `T` with a [`Prompter`].
+#[must_use]
+pub struct TextStep<'a, T>
+{
+    /// The prompter used to read and validate the value.
+    prompter: Prompter<'a, T>,
+    /// The message printed when prompting.
+    msg: &'a str,
+}
+
+impl<'a, T> TextStep<'a, T>
+{
+    /// Creates a step that prompts with `msg` using `prompter`.
+    pub fn new(prompter: Prompter<'a, T>, msg: &'a str) -> Self {
+        Self {
+            prompter,
+            msg,
+        }
+    }
+}
+
+impl<T> WizardStep for TextStep<'_, T>
+where
+    T: 'static,
+{
+    fn run(&self) -> PromptResult<Box<dyn Any>> {
+        Ok(Box::new(self.prompter.prompt(self.msg)?))
+    }
+}
+
+/// A [`WizardStep`] that prompts for a value of type `C` with a [`Select`].
+///
+/// The selection is cancellable (see [`Select::prompt_cancellable`]); cancelling it stops the wizard with [`PromptError::Cancelled`].
+#[must_use]
+pub struct SelectStep<C, D>
+where
+    C: Clone,
+    D: Display,
+{
+    /// The selection used to choose the value.
+    select: Select<C, D>,
+    /// The message printed when prompting.
+    msg: D,
+}
+
+impl<C, D> SelectStep<C, D>
+where
+    C: Clone,
+    D: Display,
+{
+    /// Creates a step that prompts with `msg` using `select`.
+    pub fn new(select: Select<C, D>, msg: D) -> Self {
+        Self {
+            select,
+            msg,
+        }
+    }
+}
+
+impl<C, D> WizardStep for SelectStep<C, D>
+where
+    C: Clone + 'static,
+    D: Display + Clone,
+{
+    fn run(&self) -> PromptResult<Box<dyn Any>> {
+        let value = self.select.prompt_cancellable(self.msg.clone())?;
+        let value = value.ok_or(PromptError::Cancelled)?;
+        Ok(Box::new(value))
+    }
+}
+
+/// Runs a sequence of [`WizardStep`]s in order, gathering their results.
+#[must_use]
+#[derive(Default)]
+pub struct Wizard<'a> {
+    /// The steps to run, in order.
+    steps: Vec<Box<dyn WizardStep + 'a>>,
+    /// Renders the header printed before each step, if any.
+    step_header: Option<StepHeaderFn<'a>>,
+}
+
+impl<'a> Wizard<'a> {
+    /// Creates a wizard without any steps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes the existing `Wizard` and returns a new `Wizard` that runs `step` after the existing steps.
+    pub fn step(mut self, step: impl WizardStep + 'a) -> Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    /// Consumes the existing `Wizard` and returns a new `Wizard` that calls `render_fn` before each step, with the (one-based) step number and the total number of steps.
+    pub fn with_step_header(mut self, render_fn: StepHeaderFn<'a>) -> Self {
+        self.step_header = Some(render_fn);
+        self
+    }
+
+    /// Runs every step in order, returning their results in the same order.
+    /// Each result can be recovered with [`downcast()`](Box::downcast) to the type of value its step prompted for.
+    ///
+    /// # Errors
+    /// Stops at (and returns) the first error returned by a step, such as [`PromptError::Cancelled`] when the user presses Ctrl+C or cancels a selection.
+    pub fn run_all(&self) -> PromptResult<Vec<Box<dyn Any>>> {
+        let total = self.steps.len();
+        let mut results = Vec::with_capacity(total);
+        for (i, step) in self.steps.iter().enumerate() {
+            if let Some(step_header) = &self.step_header {
+                step_header(i + 1, total);
+            }
+            results.push(step.run()?);
+        }
+        Ok(results)
+    }
+}
@@ -0,0 +1,37 @@
+use prediput::binary::decode_hex;
+
+#[test]
+fn decode_hex_round_trips() {
+    let bytes = vec![0x00, 0x7f, 0xab, 0xff];
+    let encoded: String = bytes.iter().map(|byte| format!("{byte:02X}")).collect();
+    assert_eq!(decode_hex(&encoded), Ok(bytes));
+    assert_eq!(decode_hex("deadBEEF"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+}
+
+#[test]
+fn decode_hex_rejects_odd_length() {
+    assert_eq!(decode_hex("abc"), Err("Hex input must have an even number of digits (found 3).".to_string()));
+}
+
+#[test]
+fn decode_hex_names_invalid_digit() {
+    assert_eq!(decode_hex("00g1"), Err("Invalid hex digit `g` at position 3.".to_string()));
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn decode_base64_round_trips() {
+    use prediput::binary::decode_base64;
+
+    let bytes = b"prediput".to_vec();
+    assert_eq!(decode_base64(&base64::encode(&bytes)), Ok(bytes));
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn decode_base64_rejects_invalid_padding() {
+    use prediput::binary::decode_base64;
+
+    assert_eq!(decode_base64("cHJlZGk=="), Err("Base64 input has an invalid length (9), which may be due to missing or extra padding.".to_string()));
+    assert_eq!(decode_base64("cHJl=GlwdXQ="), Err("Invalid base64 character `=` at position 5.".to_string()));
+}
@@ -0,0 +1,46 @@
+use prediput::{confirm_env, PromptError};
+use prediput::prompting::{Predicate, Prompter};
+
+#[test]
+fn env_default_skips_prompt_when_valid() {
+    std::env::set_var("PREDIPUT_TEST_PORT", " 8080 ");
+    let prompter = Prompter::<u16>::new("not a port")
+        .pred(Predicate::new("must be non-zero", Box::new(|port: &u16| *port != 0)))
+        .env_default("PREDIPUT_TEST_PORT");
+    assert_eq!(prompter.prompt("Port: ").unwrap(), 8080);
+}
+
+#[test]
+fn confirm_env_reads_answer() {
+    std::env::set_var("PREDIPUT_TEST_YES", "Yes");
+    std::env::set_var("PREDIPUT_TEST_NO", "0");
+    assert!(confirm_env("Continue? ", false, "PREDIPUT_TEST_YES").unwrap());
+    assert!(!confirm_env("Continue? ", false, "PREDIPUT_TEST_NO").unwrap());
+}
+
+#[test]
+fn env_default_uses_conversion_fn() {
+    std::env::set_var("PREDIPUT_TEST_CSV", "1,2,3");
+    let prompter = Prompter::<Vec<u8>>::from_conversion_fn("not a list", Box::new(|val| val.split(',').map(|n| n.parse().map_err(|_| format!("{n:?} is not a number"))).collect()))
+        .pred(Predicate::new("too many values", Box::new(|vals: &Vec<u8>| vals.len() <= 3)))
+        .env_default("PREDIPUT_TEST_CSV");
+    assert_eq!(prompter.prompt("Values: ").unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn prompt_or_env_errs_on_invalid_value() {
+    std::env::set_var("PREDIPUT_TEST_VERSION", "1.2.3");
+    let err = Prompter::<u32>::new("not a number").prompt_or_env("Version: ", "PREDIPUT_TEST_VERSION").unwrap_err();
+    assert!(matches!(err, PromptError::ValidationFailed(_)));
+    assert_eq!(err.to_string(), "invalid value for environment variable `PREDIPUT_TEST_VERSION`: not a number");
+
+    std::env::set_var("PREDIPUT_TEST_BUILD", "12");
+    assert_eq!(Prompter::<u32>::new("not a number").prompt_or_env("Build: ", "PREDIPUT_TEST_BUILD").unwrap(), 12);
+}
+
+#[test]
+fn prompt_nonblocking_sends_result() {
+    std::env::set_var("PREDIPUT_TEST_THREADS", "4");
+    let handle = Prompter::prompt_nonblocking(|| Prompter::<u8>::new("not a number").env_default("PREDIPUT_TEST_THREADS"), "Threads: ");
+    assert_eq!(handle.wait().unwrap(), 4);
+}
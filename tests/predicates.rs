@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use prediput::predicates::{in_range, max_length, min_length, non_empty, one_of};
+use prediput::prompting::Predicate;
+
+#[test]
+fn length_predicates_count_graphemes() {
+    assert!(!non_empty("empty").validate(&String::new()));
+    assert!(min_length(2, "too short").validate(&"héé".to_string()));
+    assert!(max_length(3, "too long").validate(&"héé".to_string()));
+    assert!(!max_length(2, "too long").validate(&"héé".to_string()));
+}
+
+#[test]
+fn in_range_is_inclusive() {
+    let pred = in_range(1u32, 100u32, "must be between 1 and 100");
+    assert!(pred.validate(&1) && pred.validate(&100));
+    assert!(!pred.validate(&0) && !pred.validate(&101));
+    assert_eq!(pred.invalid_msg(), "must be between 1 and 100");
+}
+
+#[test]
+fn one_of_matches_exactly() {
+    let pred = one_of(&["red", "green"], "unknown colour");
+    assert!(pred.validate(&"green".to_string()));
+    assert!(!pred.validate(&"Green".to_string()));
+}
+
+#[test]
+fn and_reports_first_failing_message() {
+    let pred = min_length(2, "too short").and(max_length(4, "too long"));
+    assert_eq!(pred.check(&"abc".to_string()), Ok(()));
+    assert_eq!(pred.check(&"a".to_string()), Err("too short".into()));
+    assert_eq!(pred.check(&"abcde".to_string()), Err("too long".into()));
+}
+
+#[test]
+fn or_passes_when_either_passes() {
+    let pred = in_range(0, 9, "not a digit").or(in_range(100, 999, "not three digits"));
+    assert!(pred.validate(&5) && pred.validate(&500));
+    assert_eq!(pred.check(&50), Err("not a digit".into()));
+}
+
+#[test]
+fn not_inverts_with_stored_message() {
+    let pred = one_of(&["admin", "root"], "reserved name").not();
+    assert!(pred.validate(&"alice".to_string()));
+    assert_eq!(pred.check(&"root".to_string()), Err("reserved name".into()));
+}
+
+#[test]
+fn not_of_message_fn_predicate_generates_message() {
+    let pred = Predicate::with_message_fn(Box::new(|n: &u32| *n > 10), Box::new(|n| format!("{n} is too big"))).not();
+    assert!(pred.validate(&5));
+    assert_eq!(pred.check(&42), Err("42 is too big".into()));
+    assert_eq!(pred.invalid_msg_for(&42), "42 is too big");
+}
+
+#[test]
+fn combinators_chain() {
+    // (non-empty and short) or "-", excluding "x"
+    let pred = non_empty("empty")
+        .and(max_length(3, "too long"))
+        .or(one_of(&["-"], "not a dash"))
+        .and(one_of(&["x"], "x is not allowed").not());
+    assert!(pred.validate(&"ab".to_string()));
+    assert!(pred.validate(&"-".to_string()));
+    assert_eq!(pred.check(&"abcd".to_string()), Err("too long".into()));
+    assert_eq!(pred.check(&"x".to_string()), Err("x is not allowed".into()));
+    assert_eq!(pred.check(&String::new()), Err("empty".into()));
+}
+
+#[test]
+fn message_fn_references_value() {
+    let pred = Predicate::with_message_fn(Box::new(|n: &u32| (1..=10).contains(n)), Box::new(|n| format!("{n} is not in range [1, 10]")));
+    assert!(pred.validate(&5));
+    assert_eq!(pred.invalid_msg_for(&42), "42 is not in range [1, 10]");
+    assert_eq!(pred.and(in_range(1, 5, "too big")).invalid_msg_for(&7), "too big");
+}
+
+#[test]
+fn stateful_predicate_remembers_values() {
+    let unique = Predicate::new_stateful(HashSet::new(), "already entered", Box::new(|val: &String, seen: &mut HashSet<String>| seen.insert(val.clone())));
+    assert!(unique.validate(&"a".to_string()));
+    assert!(unique.validate(&"b".to_string()));
+    assert_eq!(unique.check(&"a".to_string()), Err("already entered".into()));
+}
+
+#[test]
+fn transform_then_validate_checks_derived_value() {
+    let not_reserved = Predicate::transform_then_validate(Box::new(|val: &String| val.to_uppercase()), Box::new(|upper: &String| upper != "NULL"), "reserved word");
+    assert!(not_reserved.validate(&"value".to_string()));
+    assert_eq!(not_reserved.check(&"Null".to_string()), Err("reserved word".into()));
+}
@@ -0,0 +1,256 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use console::Key;
+use prediput::select::{KeyMap, MultiSelect, Select, SelectOpt};
+use prediput::{style, Decolor, PromptError};
+use prediput::theme::{set_default_theme, Theme};
+
+fn fruit_select() -> Select<u32, &'static str> {
+    Select::new(
+        "> ",
+        vec![
+            SelectOpt::new("Apple", None, 1),
+            SelectOpt::new("Banana", None, 2),
+            SelectOpt::new("Cherry", None, 3),
+        ],
+    )
+}
+
+#[test]
+fn simulate_returns_value_under_cursor() {
+    assert_eq!(fruit_select().simulate(vec![Key::ArrowDown, Key::Enter]).unwrap(), 2);
+}
+
+#[test]
+fn simulate_wraps_around() {
+    assert_eq!(fruit_select().simulate(vec![Key::ArrowUp, Key::Enter]).unwrap(), 3);
+}
+
+#[test]
+fn simulate_errs_without_confirmation() {
+    let err = fruit_select().simulate(vec![Key::ArrowDown]).unwrap_err();
+    assert!(matches!(err, PromptError::Io(ref err) if err.kind() == std::io::ErrorKind::UnexpectedEof));
+}
+
+#[test]
+fn simulate_errs_on_ctrl_c() {
+    let err = fruit_select().simulate(vec![Key::ArrowDown, Key::CtrlC, Key::Enter]).unwrap_err();
+    assert!(matches!(err, PromptError::Cancelled));
+}
+
+#[test]
+fn render_frame_marks_selected_option() {
+    assert_eq!(fruit_select().render_frame(1), "Apple\n> Banana\nCherry");
+    assert_eq!(fruit_select().aligned().render_frame(0), "> Apple\n  Banana\n  Cherry");
+}
+
+#[test]
+fn simulate_with_vim_keymap() {
+    let sel = fruit_select().keymap(KeyMap::vim());
+    assert_eq!(sel.simulate(vec![Key::Char('j'), Key::Char('j'), Key::Char('k'), Key::Char('l')]).unwrap(), 2);
+    let sel = fruit_select().keymap(KeyMap::vim());
+    assert_eq!(sel.simulate(vec![Key::ArrowDown, Key::Enter]).unwrap(), 1);
+}
+
+#[test]
+fn simulate_with_wasd_keymap() {
+    let sel = fruit_select().keymap(KeyMap::wasd());
+    assert_eq!(sel.simulate(vec![Key::Char('w'), Key::Char('d')]).unwrap(), 3);
+}
+
+#[test]
+fn simulate_with_arrow_fallback() {
+    let sel = fruit_select().arrow_fallback();
+    assert_eq!(sel.simulate(vec![Key::Char('j'), Key::ArrowDown, Key::Char('k'), Key::Enter]).unwrap(), 2);
+    assert_eq!(fruit_select().simulate(vec![Key::Char('j'), Key::Enter]).unwrap(), 1);
+}
+
+#[test]
+fn simulate_calls_on_change() {
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let sel = fruit_select().on_change({
+        let changes = Rc::clone(&changes);
+        move |i, value| changes.borrow_mut().push((i, *value))
+    });
+    assert_eq!(sel.simulate(vec![Key::ArrowDown, Key::PageUp, Key::ArrowUp, Key::Enter]).unwrap(), 3);
+    assert_eq!(*changes.borrow(), vec![(0, 1), (1, 2), (0, 1), (2, 3)]);
+}
+
+#[test]
+fn simulate_home_and_end_jump() {
+    assert_eq!(fruit_select().simulate(vec![Key::End, Key::Enter]).unwrap(), 3);
+    assert_eq!(fruit_select().visible_rows(2).simulate(vec![Key::End, Key::Home, Key::Enter]).unwrap(), 1);
+}
+
+#[test]
+fn decolor_borrows_plain_text() {
+    assert!(matches!("plain".decolored(), Cow::Borrowed("plain")));
+    assert_eq!("\x1b[1mbold\x1b[0m text".decolored(), "bold text");
+    assert_eq!("\x1b[31mred".to_string().decolored(), "red");
+}
+
+#[test]
+fn decolor_strips_other_escape_sequences() {
+    assert_eq!("\x1b[2Aup\x1b[1;5Hhome\x1b[K".decolored(), "uphome");
+    assert_eq!("\x1b]8;;https://example.com\x07link\x1b]8;;\x07".decolored(), "link");
+    assert_eq!("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\".decolored(), "link");
+    assert_eq!("a\x1bMb".decolored(), "ab");
+    assert_eq!("text\x1b[31".decolored(), "text\x1b[31");
+    assert_eq!("text\x1b".decolored(), "text\x1b");
+}
+
+#[test]
+fn aligned_prefix_ignores_hyperlinks() {
+    let prefix = "\x1b]8;;https://example.com\x1b\\>\x1b]8;;\x1b\\ ";
+    let sel = Select::new(prefix, vec![SelectOpt::new("Apple", None, 1), SelectOpt::new("Banana", None, 2)]).aligned();
+    assert_eq!(sel.render_frame(0).decolored(), "> Apple\n  Banana");
+}
+
+#[test]
+fn simulate_returns_cloned_values() {
+    let sel = Select::new("> ", vec![SelectOpt::new("Short", None, "s".to_string()), SelectOpt::new("Long", None, "long".to_string())]);
+    assert_eq!(sel.simulate(vec![Key::ArrowDown, Key::Enter]).unwrap(), "long");
+}
+
+#[test]
+fn separators_render_between_options() {
+    let sel = fruit_select().add_separator_after(0);
+    assert_eq!(sel.render_frame(1), "Apple\n──────\n> Banana\nCherry");
+    let sel = fruit_select().add_separator_after(0);
+    assert_eq!(sel.simulate(vec![Key::ArrowDown, Key::Enter]).unwrap(), 2);
+}
+
+#[test]
+fn render_frame_uses_theme() {
+    let theme = Theme { select_prefix: "* ".to_string(), select_aligned: true, ..Theme::default() };
+    assert_eq!(fruit_select().with_theme(&theme).render_frame(0), "* Apple\n  Banana\n  Cherry");
+    assert_eq!(fruit_select().with_theme(&theme).prefix("-> ").render_frame(0), "-> Apple\n   Banana\n   Cherry");
+
+    set_default_theme(Theme { select_aligned: true, ..Theme::default() });
+    assert_eq!(fruit_select().render_frame(0), "> Apple\n  Banana\n  Cherry");
+}
+
+#[test]
+fn render_frame_draws_scrollbar() {
+    assert_eq!(fruit_select().visible_rows(2).render_frame(0), "> Apple █\nBanana  │");
+    assert_eq!(fruit_select().visible_rows(2).render_frame(2), "Banana   │\n> Cherry █");
+    assert_eq!(fruit_select().visible_rows(2).no_scrollbar().render_frame(0), "> Apple\nBanana");
+    assert_eq!(fruit_select().visible_rows(3).render_frame(0), "> Apple\nBanana\nCherry");
+}
+
+#[test]
+fn inline_renders_one_line_and_moves_horizontally() {
+    assert_eq!(fruit_select().inline().render_frame(1), "Apple  > Banana  Cherry");
+    assert_eq!(fruit_select().inline().aligned().render_frame(0), "> Apple    Banana    Cherry");
+    assert_eq!(fruit_select().inline().simulate(vec![Key::ArrowRight, Key::ArrowDown, Key::Enter]).unwrap(), 2);
+}
+
+#[test]
+fn with_search_filters_by_substring() {
+    let keys = |query: &str| query.chars().map(Key::Char).chain([Key::Enter]).collect::<Vec<_>>();
+    assert_eq!(fruit_select().with_search().simulate(keys("ERR")).unwrap(), 3);
+    assert_eq!(fruit_select().searchable().simulate(keys("cry")).unwrap(), 3);
+    let err = fruit_select().with_search().simulate(keys("cry")).unwrap_err();
+    assert!(matches!(err, PromptError::Io(ref err) if err.kind() == std::io::ErrorKind::UnexpectedEof));
+}
+
+#[test]
+fn render_frame_shows_disabled_reason() {
+    let sel = fruit_select().opt(SelectOpt::new("Durian", None, 4).disable_with_reason("sold out"));
+    assert_eq!(sel.render_frame(0), "> Apple\nBanana\nCherry\nDurian  (sold out)");
+}
+
+#[test]
+fn simulate_adds_option_from_add_entry() {
+    let sel = Select::new("> ".to_string(), vec![SelectOpt::new("1".to_string(), None, 1), SelectOpt::new("2".to_string(), None, 2)])
+        .with_add_option("+ Add".to_string(), Box::new(|| Ok(42)));
+    assert_eq!(sel.render_frame(2), "1\n2\n> + Add");
+
+    // choosing the entry moves the cursor onto the added option, and it can be confirmed from there
+    let keys = vec![Key::End, Key::Enter, Key::ArrowDown, Key::ArrowUp, Key::Enter];
+    assert_eq!(sel.simulate(keys).unwrap(), 42);
+}
+
+#[test]
+fn styled_prefix_aligns_by_visible_width() {
+    let sel = Select::new(style::ARROW_PREFIX, vec![SelectOpt::new("Apple", None, 1), SelectOpt::new("Banana", None, 2)]).aligned();
+    assert_eq!(sel.render_frame(0).decolored(), "➤  Apple\n   Banana");
+}
+
+#[test]
+fn out_of_bounds_default_errs() {
+    let err = fruit_select().default_opt(99).simulate(vec![Key::Enter]).unwrap_err();
+    assert!(matches!(err, PromptError::InvalidDefault(_)));
+    assert_eq!(err.to_string(), "the default option index (99) is out of bounds for a selection with 3 options");
+
+    let err = Select::<u32, &str>::new("> ", Vec::new()).simulate(vec![Key::Enter]).unwrap_err();
+    assert!(matches!(err, PromptError::InvalidDefault(_)));
+}
+
+#[test]
+fn disabled_default_errs() {
+    let select = Select::new("> ", Vec::new()).default_opt(0).opt(SelectOpt::new_disabled("Apple", None, 1)).opt(SelectOpt::new("Banana", None, 2));
+    let err = select.simulate(vec![Key::Enter]).unwrap_err();
+    assert!(matches!(err, PromptError::InvalidDefault(_)));
+    assert_eq!(err.to_string(), "the default option (at index 0) is disabled");
+}
+
+#[test]
+fn numbered_renders_numbers_and_jumps_to_digits() {
+    assert_eq!(fruit_select().numbered().aligned().render_frame(1), "  1. Apple\n> 2. Banana\n  3. Cherry");
+    assert_eq!(fruit_select().numbered().simulate(vec![Key::Char('3')]).unwrap(), 3);
+
+    // with more than 9 options, a digit only moves the cursor
+    let many = (1..=10).map(|n| SelectOpt::new("Option", None, n)).collect();
+    let sel = Select::new("> ", many).numbered();
+    assert_eq!(sel.render_frame(9).lines().last(), Some("> 10. Option"));
+    assert_eq!(sel.simulate(vec![Key::Char('4'), Key::ArrowDown, Key::Enter]).unwrap(), 5);
+}
+
+#[test]
+fn sortable_toggles_alphabetical_order() {
+    let sel = Select::new("> ", vec![SelectOpt::new("Cherry", None, 3), SelectOpt::new("apple", None, 1), SelectOpt::new("Banana", None, 2)]).sortable();
+    // sorted, the cursor stays on "Cherry", which moves between "Banana" and "apple" (sorted last, since lowercase letters come after uppercase ones)
+    assert_eq!(sel.simulate(vec![Key::Char('s'), Key::ArrowUp, Key::Enter]).unwrap(), 2);
+
+    let sel = Select::new("> ", vec![SelectOpt::new("Cherry", None, 3), SelectOpt::new("apple", None, 1), SelectOpt::new("Banana", None, 2)]).sortable();
+    assert_eq!(sel.simulate(vec![Key::Char('s'), Key::Char('s'), Key::ArrowDown, Key::Enter]).unwrap(), 1);
+}
+
+#[test]
+fn typed_character_jumps_to_matching_option() {
+    let select = || Select::new("> ", vec![
+        SelectOpt::new("Apple", None, 1),
+        SelectOpt::new("banana", None, 2),
+        SelectOpt::new("Blueberry", None, 3),
+        SelectOpt::new("Cherry", None, 4),
+    ]);
+    assert_eq!(select().simulate(vec![Key::Char('c'), Key::Enter]).unwrap(), 4);
+    assert_eq!(select().simulate(vec![Key::Char('B'), Key::Enter]).unwrap(), 2);
+    assert_eq!(select().simulate(vec![Key::Char('b'), Key::Char('b'), Key::Enter]).unwrap(), 3);
+    assert_eq!(select().simulate(vec![Key::Char('b'), Key::Char('b'), Key::Char('b'), Key::Enter]).unwrap(), 2);
+    assert_eq!(select().simulate(vec![Key::Char('z'), Key::Enter]).unwrap(), 1);
+}
+
+#[test]
+fn multi_select_errs_without_options() {
+    let err = MultiSelect::<u32, &str>::new("> ", Vec::new()).prompt("Pick:").unwrap_err();
+    assert!(matches!(err, PromptError::InvalidDefault(_)));
+}
+
+#[test]
+fn multi_select_errs_on_out_of_bounds_default() {
+    let sel = MultiSelect::new("> ", vec![SelectOpt::new("Apple", None, 1), SelectOpt::new("Banana", None, 2)]).default_opt(2);
+    let err = sel.prompt("Pick:").unwrap_err();
+    assert!(matches!(err, PromptError::InvalidDefault(_)));
+    assert_eq!(err.to_string(), "the default option index (2) is out of bounds for a selection with 2 options");
+}
+
+#[test]
+fn truncation_keeps_scrollbar() {
+    let sel = fruit_select().opt(SelectOpt::new("Dragon fruit, which is a very long name", None, 4)).visible_rows(2);
+    assert_eq!(sel.render_frame_truncated(3, 16), "Cherry         │\n> Dragon frui… █");
+    assert_eq!(sel.no_truncate().render_frame_truncated(0, 16), "> Apple █\nBanana  │");
+}
@@ -0,0 +1,214 @@
+use std::cell::RefCell;
+
+use console::Style;
+
+use prediput::confirm::Confirm;
+use prediput::predicates::{in_range, min_length};
+use prediput::prompting::{Predicate, Prompter, TryFromPrompter};
+use prediput::select::{Select, SelectOpt};
+use prediput::testing::MockTerminal;
+use prediput::{PromptError, TrimMode};
+
+fn inputs(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn prompter_records_rejections() {
+    let mock = MockTerminal::new(inputs(&["0", "7"]));
+    let prompter = Prompter::<u8>::new("Please enter a number.").pred(in_range(1, 10, "Out of range."));
+    assert_eq!(prompter.prompt_with_backend("Pick: ", &mock).unwrap(), 7);
+    assert_eq!(mock.output(), vec!["Pick: 0", "Out of range.", "Pick: 7"]);
+}
+
+#[test]
+fn select_then_confirm() {
+    let mock = MockTerminal::new(inputs(&["banana", "y"]));
+    let select = Select::new("> ", vec![SelectOpt::new("Apple", None, 1), SelectOpt::new("Banana", None, 2)]);
+    assert_eq!(select.prompt_with_backend("Fruit?", &mock).unwrap(), 2);
+    assert!(Confirm::new("Sure? ").default(false).prompt_with_backend(&mock).unwrap());
+    assert_eq!(mock.output(), vec!["Fruit?", "> Apple", "Banana", "banana", "Sure? [y/N] y"]);
+}
+
+#[test]
+fn mock_errs_when_out_of_inputs() {
+    let mock = MockTerminal::new(Vec::new());
+    let err = Prompter::<u8>::new("Please enter a number.").prompt_with_backend("Pick: ", &mock).unwrap_err();
+    assert!(matches!(err, PromptError::Io(ref err) if err.kind() == std::io::ErrorKind::UnexpectedEof));
+}
+
+/// A username, which can't contain spaces.
+#[derive(Debug, PartialEq, Eq)]
+struct Username(String);
+
+impl TryFrom<String> for Username {
+    type Error = &'static str;
+
+    fn try_from(val: String) -> Result<Self, Self::Error> {
+        if val.contains(' ') { Err("usernames can't contain spaces") } else { Ok(Self(val)) }
+    }
+}
+
+#[test]
+fn try_from_prompter_reports_conversion_error() {
+    let mock = MockTerminal::new(inputs(&["john doe", "jdoe"]));
+    let username = TryFromPrompter::<Username>::new("Please enter a username.").prompt_with_backend("Username: ", &mock).unwrap();
+    assert_eq!(username, Username("jdoe".to_string()));
+    assert_eq!(mock.output(), vec!["Username: john doe", "Please enter a username. (usernames can't contain spaces)", "Username: jdoe"]);
+}
+
+#[test]
+fn echo_mask_hides_input() {
+    let mock = MockTerminal::new(inputs(&["hunter", "hunter22"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(8, "Too short.")).with_echo_mask('*');
+    assert_eq!(prompter.prompt_with_backend("Password: ", &mock).unwrap(), "hunter22");
+    assert_eq!(mock.output(), vec!["Password: ******", "Too short.", "Password: ********"]);
+}
+
+#[test]
+fn report_all_errors_prints_every_failure() {
+    let has_digit = Predicate::new("Must contain a number.", Box::new(|val: &String| val.chars().any(|c| c.is_ascii_digit())));
+    let mock = MockTerminal::new(inputs(&["hunter", "hunter22"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(8, "Too short.")).pred(has_digit).report_all_errors();
+    assert_eq!(prompter.prompt_with_backend("Password: ", &mock).unwrap(), "hunter22");
+    assert_eq!(mock.output(), vec!["Password: hunter", "Too short.\nMust contain a number.", "Password: hunter22"]);
+}
+
+#[test]
+fn attempt_hook_runs_before_each_attempt() {
+    let attempts = RefCell::new(Vec::new());
+    let mock = MockTerminal::new(inputs(&["1", "2", "3"]));
+    let prompter = Prompter::<u32>::new("").pred(in_range(5, 10, "Out of range.")).max_attempts(2).with_attempt_hook(|n| attempts.borrow_mut().push(n));
+    assert!(prompter.prompt_with_backend("Number: ", &mock).is_err());
+    assert_eq!(*attempts.borrow(), vec![1, 2]);
+}
+
+#[test]
+fn trim_mode_keeps_significant_whitespace() {
+    let mock = MockTerminal::new(inputs(&["    let x = 1;  ", "  "]));
+    assert_eq!(Prompter::<String>::new("").trim_mode(TrimMode::Right).prompt_with_backend("", &mock).unwrap(), "    let x = 1;");
+    assert_eq!(Prompter::<String>::new("").trim_mode(TrimMode::None).prompt_with_backend("", &mock).unwrap(), "  ");
+}
+
+#[test]
+fn description_prints_above_prompt() {
+    let description = ["Your API key is found in Settings > API."];
+    let mock = MockTerminal::new(inputs(&["abc", "ab", "abcd"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(4, "Too short.")).with_description(&description);
+    assert_eq!(prompter.prompt_with_backend("Key: ", &mock).unwrap(), "abcd");
+    assert_eq!(mock.output(), vec![description[0], "", "Too short.", "Key: abcd"]); // each retry redraws the block in place
+
+    let mock = MockTerminal::new(inputs(&["abc", "abcd"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(4, "Too short.")).with_description(&description).with_inline_error();
+    assert_eq!(prompter.prompt_with_backend("Key: ", &mock).unwrap(), "abcd");
+    assert_eq!(mock.output(), vec![description[0], "", "Key:   ← Too short.", "Key: abcd"]);
+
+    let mock = MockTerminal::new(inputs(&["abc", "abcd"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(4, "Too short.")).with_description_once(&description);
+    assert_eq!(prompter.prompt_with_backend("Key: ", &mock).unwrap(), "abcd");
+    assert_eq!(mock.output(), vec![description[0], "", "Key: abc", "Too short.", "Key: abcd"]);
+}
+
+#[test]
+fn warning_pred_prints_without_rejecting() {
+    let not_test = Predicate::new("That looks like a test address.", Box::new(|val: &String| !val.starts_with("test@")));
+    let mock = MockTerminal::new(inputs(&["test@example.com"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(3, "Too short.")).warning_pred(not_test);
+    assert_eq!(prompter.prompt_with_backend("Email: ", &mock).unwrap(), "test@example.com");
+
+    let output = mock.output();
+    assert_eq!(output.len(), 2);
+    assert!(output[1].ends_with(" That looks like a test address."), "{output:?}");
+}
+
+#[test]
+fn select_renders_step_header() {
+    let mock = MockTerminal::new(inputs(&["Apple"]));
+    let select = Select::new("> ", vec![SelectOpt::new("Apple", None, 1)]).with_step(2, 5);
+    assert_eq!(select.prompt_with_backend("Fruit?", &mock).unwrap(), 1);
+    assert_eq!(mock.output(), vec!["[2/5]", "Fruit?", "> Apple", "Apple"]);
+
+    let mock = MockTerminal::new(inputs(&["Apple"]));
+    let select = Select::new("> ", vec![SelectOpt::new("Apple", None, 1)]).with_step(2, 5).step_format(|current, total| format!("Step {current} of {total}"));
+    select.prompt_with_backend("Fruit?", &mock).unwrap();
+    assert_eq!(mock.output()[0], "Step 2 of 5");
+}
+
+#[test]
+fn from_pred_iter_collects_predicates() {
+    let rules = [(3, "Too short."), (5, "Still too short.")];
+    let mock = MockTerminal::new(inputs(&["ab", "abcd", "abcde"]));
+    let prompter = Prompter::<String>::from_pred_iter("", rules.iter().map(|&(n, msg)| min_length(n, msg)));
+    assert_eq!(prompter.prompt_with_backend("", &mock).unwrap(), "abcde");
+    assert_eq!(mock.output(), vec!["ab", "Too short.", "abcd", "Still too short.", "abcde"]);
+}
+
+#[test]
+fn noecho_hides_input_entirely() {
+    let mock = MockTerminal::new(inputs(&["token", "secret-token"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(8, "Too short.")).with_echo_mask('*').noecho();
+    assert_eq!(prompter.prompt_with_backend("Token: ", &mock).unwrap(), "secret-token");
+    assert_eq!(mock.output(), vec!["Token: ", "Too short.", "Token: "]);
+}
+
+#[test]
+fn select_renders_preview_below_options() {
+    let mock = MockTerminal::new(inputs(&["Apple"]));
+    let preview = |value: &u32| vec![format!("Value: {value}"), "(more details)".to_string(), "(truncated)".to_string()];
+    let select = Select::new("> ", vec![SelectOpt::new("Apple", None, 1)]).with_preview(Box::new(preview), 2);
+    select.prompt_with_backend("Fruit?", &mock).unwrap();
+    assert_eq!(mock.output(), vec!["Fruit?", "> Apple", "Value: 1", "(more details)", "Apple"]);
+
+    let mock = MockTerminal::new(inputs(&["Apple"]));
+    let select = Select::new("> ", vec![SelectOpt::new("Apple", None, 1)]).with_preview(Box::new(|_| vec!["Short".to_string()]), 2);
+    select.prompt_with_backend("Fruit?", &mock).unwrap();
+    assert_eq!(mock.output(), vec!["Fruit?", "> Apple", "Short", "", "Apple"]);
+}
+
+#[test]
+fn silent_on_success_clears_accepted_prompt() {
+    let mock = MockTerminal::new(inputs(&["ab", "abcd"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(4, "Too short.")).silent_on_success();
+    assert_eq!(prompter.prompt_with_backend("Name: ", &mock).unwrap(), "abcd");
+    assert_eq!(mock.output(), vec!["Name: ab", "Too short."]);
+
+    let mock = MockTerminal::new(inputs(&["token", "secret-token"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(8, "Too short.")).with_echo_mask('*').silent_on_success();
+    assert_eq!(prompter.prompt_with_backend("Token: ", &mock).unwrap(), "secret-token");
+    assert_eq!(mock.output(), vec!["Token: *****", "Too short.", "Token: ************"]);
+
+    let mock = MockTerminal::new(inputs(&["token", "secret-token"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(8, "Too short.")).noecho().silent_on_success();
+    assert_eq!(prompter.prompt_with_backend("Token: ", &mock).unwrap(), "secret-token");
+    assert_eq!(mock.output(), vec!["Token: ", "Too short.", "Token: "]);
+}
+
+#[test]
+fn prompt_csv_validates_each_value() {
+    let mock = MockTerminal::new(inputs(&["1, x, 42", "1; 5;", "1;5"]));
+    let prompter = Prompter::<u32>::new("Not a number.").pred(in_range(1, 10, "Out of range.")).csv_separator(';');
+    assert_eq!(prompter.prompt_csv_with_backend("Numbers: ", &mock).unwrap(), vec![1, 5]);
+    assert_eq!(mock.output(), vec!["Numbers: 1, x, 42", "Not a number. (`1, x, 42`)", "Numbers: 1; 5;"]);
+
+    let mock = MockTerminal::new(inputs(&["3, x, 42"]));
+    let prompter = Prompter::<u32>::new("Not a number.").pred(in_range(1, 10, "Out of range."));
+    assert!(prompter.max_attempts(1).prompt_csv_with_backend("Numbers: ", &mock).is_err());
+    assert_eq!(mock.output(), vec!["Numbers: 3, x, 42", "Not a number. (`x`)", "Out of range. (`42`)"]);
+}
+
+#[test]
+fn styled_predicate_prints_styled_message() {
+    let red = Style::new().red().force_styling(true);
+    let mock = MockTerminal::new(inputs(&["ab", "abcd"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(4, "Too short.").with_style(red.clone()));
+    assert_eq!(prompter.prompt_with_backend("Key: ", &mock).unwrap(), "abcd");
+    assert_eq!(mock.output(), vec!["Key: ab".to_string(), red.apply_to("Too short.").to_string(), "Key: abcd".to_string()]);
+}
+
+#[test]
+fn inline_error_replaces_rejected_line() {
+    let mock = MockTerminal::new(inputs(&["ab", "abcd"]));
+    let prompter = Prompter::<String>::new("").pred(min_length(4, "Too short.")).with_inline_error();
+    assert_eq!(prompter.prompt_with_backend("Key: ", &mock).unwrap(), "abcd");
+    assert_eq!(mock.output(), vec!["Key:   ← Too short.", "Key: abcd"]);
+}
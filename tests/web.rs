@@ -0,0 +1,26 @@
+#![cfg(feature = "url")]
+
+use prediput::prompting::{Predicate, Prompter};
+use prediput::testing::MockTerminal;
+use prediput::web::parse_url;
+use url::Url;
+
+#[test]
+fn parse_url_suggests_missing_scheme() {
+    assert_eq!(parse_url("https://example.com/a").map(String::from), Ok("https://example.com/a".to_string()));
+    assert_eq!(parse_url("example.com/a"), Err("Missing scheme (did you mean `https://example.com/a`?).".to_string()));
+}
+
+#[test]
+fn parse_url_rejects_invalid_host() {
+    assert_eq!(parse_url("https://exa mple.com"), Err("Invalid host.".to_string()));
+    assert_eq!(parse_url("http://"), Err("Invalid host.".to_string()));
+}
+
+#[test]
+fn url_scheme_requires_scheme() {
+    let mock = MockTerminal::new(vec!["http://example.com".to_string(), "HTTPS://example.com".to_string()]);
+    let prompter = Prompter::<Url>::new("Invalid URL.").pred(Predicate::url_scheme("https", "Please use https."));
+    assert_eq!(prompter.prompt_with_backend("URL: ", &mock).unwrap().as_str(), "https://example.com/");
+    assert_eq!(mock.output(), vec!["URL: http://example.com", "Please use https.", "URL: HTTPS://example.com"]);
+}
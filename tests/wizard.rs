@@ -0,0 +1,34 @@
+use std::cell::RefCell;
+use prediput::wizard::{Wizard, WizardStep};
+use prediput::{PromptError, PromptResult};
+
+/// A step that returns a fixed result without prompting.
+struct FixedStep(Option<u32>);
+
+impl WizardStep for FixedStep {
+    fn run(&self) -> PromptResult<Box<dyn std::any::Any>> {
+        match self.0 {
+            Some(val) => Ok(Box::new(val)),
+            None => Err(PromptError::Cancelled),
+        }
+    }
+}
+
+#[test]
+fn run_all_collects_results_in_order() {
+    let headers = RefCell::new(Vec::new());
+    let wizard = Wizard::new()
+        .step(FixedStep(Some(1)))
+        .step(FixedStep(Some(2)))
+        .with_step_header(Box::new(|step, total| headers.borrow_mut().push((step, total))));
+
+    let results: Vec<u32> = wizard.run_all().unwrap().into_iter().map(|r| *r.downcast::<u32>().unwrap()).collect();
+    assert_eq!(results, vec![1, 2]);
+    assert_eq!(*headers.borrow(), vec![(1, 2), (2, 2)]);
+}
+
+#[test]
+fn run_all_stops_when_interrupted() {
+    let wizard = Wizard::new().step(FixedStep(None)).step(FixedStep(Some(2)));
+    assert!(matches!(wizard.run_all().unwrap_err(), PromptError::Cancelled));
+}